@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::Style,
+    widgets::{Block, Widget},
+};
+
+use crate::ValueBar;
+
+/// A chart of many labeled [ValueBar]s sharing a common zero axis
+#[derive(Debug, Clone)]
+pub struct BipolarBarChart<'a> {
+    data: Vec<(Cow<'a, str>, f32)>,
+    direction: Direction,
+    bar_width: u16,
+    bar_gap: u16,
+    range: Option<f32>,
+    bar_style: Style,
+    label_style: Style,
+    style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Default for BipolarBarChart<'a> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            direction: Direction::Vertical,
+            bar_width: 1,
+            bar_gap: 1,
+            range: None,
+            bar_style: Style::default(),
+            label_style: Style::default(),
+            style: Style::default(),
+            block: None,
+        }
+    }
+}
+
+impl<'a> BipolarBarChart<'a> {
+    /// Add labeled values to the chart, one bar per entry
+    pub fn data<T>(mut self, data: &[(T, f32)]) -> Self
+    where
+        T: Into<Cow<'a, str>> + Clone,
+    {
+        self.data.extend(
+            data.iter()
+                .map(|(label, value)| (label.clone().into(), *value)),
+        );
+        self
+    }
+
+    /// Set whether the bars grow vertically (default) or horizontally
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Thickness of each bar in cells. Defaults to 1.
+    pub fn bar_width(mut self, width: u16) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Number of cells between two neighbouring bars. Defaults to 1.
+    pub fn bar_gap(mut self, gap: u16) -> Self {
+        self.bar_gap = gap;
+        self
+    }
+
+    /// The upper and lower bound shared by all bars.
+    /// If not set, the largest absolute value of the data is used.
+    pub fn range(mut self, range: f32) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Style applied to every bar
+    pub fn bar_style(mut self, style: Style) -> Self {
+        self.bar_style = style;
+        self
+    }
+
+    /// Style applied to the category labels
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Style applied to the whole chart area
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Surround this chart by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    fn effective_range(&self) -> f32 {
+        let range = self.range.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .map(|(_, value)| value.abs())
+                .fold(0., f32::max)
+        });
+        if range > 0. {
+            range
+        } else {
+            1.
+        }
+    }
+
+    fn bar(&self, value: f32) -> ValueBar<'a> {
+        ValueBar::default()
+            .value(value)
+            .range(self.effective_range())
+            .direction(self.direction)
+            .style(self.bar_style)
+    }
+
+    fn render_vertical(&self, area: Rect, buffer: &mut Buffer) {
+        if area.height < 2 {
+            // Not enough space for bars and labels
+            return;
+        }
+        let bars = Rect {
+            height: area.height - 1,
+            ..area
+        };
+        let mut x = area.left();
+        for (label, value) in &self.data {
+            if x >= area.right() {
+                break;
+            }
+            let width = self.bar_width.min(area.right() - x);
+            self.bar(*value).render(Rect { x, width, ..bars }, buffer);
+
+            let label: String = label.chars().take(width as usize).collect();
+            let offset = (width - label.chars().count() as u16) / 2;
+            buffer.set_string(x + offset, area.bottom() - 1, label, self.label_style);
+
+            x = x.saturating_add(self.bar_width + self.bar_gap);
+        }
+    }
+
+    fn render_horizontal(&self, area: Rect, buffer: &mut Buffer) {
+        let label_width = self
+            .data
+            .iter()
+            .map(|(label, _)| label.chars().count() as u16)
+            .max()
+            .unwrap_or(0)
+            .min(area.width / 2);
+        let bars = Rect {
+            x: area.left() + label_width + 1,
+            width: area.width.saturating_sub(label_width + 1),
+            ..area
+        };
+        let mut y = area.top();
+        for (label, value) in &self.data {
+            if y >= area.bottom() {
+                break;
+            }
+            let height = self.bar_width.min(area.bottom() - y);
+            self.bar(*value).render(Rect { y, height, ..bars }, buffer);
+
+            let label: String = label.chars().take(label_width as usize).collect();
+            buffer.set_string(area.left(), y + (height - 1) / 2, label, self.label_style);
+
+            y = y.saturating_add(self.bar_width + self.bar_gap);
+        }
+    }
+}
+
+impl<'a> Widget for BipolarBarChart<'a> {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        buffer.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        if area.width < 1 || area.height < 1 || self.bar_width < 1 {
+            // Not enough space to render?
+            return;
+        }
+        match self.direction {
+            Direction::Horizontal => self.render_horizontal(area, buffer),
+            Direction::Vertical => self.render_vertical(area, buffer),
+        }
+    }
+}
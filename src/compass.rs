@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Widget},
+};
+
+use crate::{geometry::wrap, BarValue};
+
+/// A horizontal tape of ticks and labels scrolling past a marker at the current heading
+///
+/// The axis wraps around by the [period](CompassTape::period), so the tape stays continuous when
+/// the heading crosses from 359° to 0°. The top row shows the labels of the major ticks, the
+/// next one the ticks and the one below a `▲` marking the heading in the center. Smaller areas
+/// drop the marker row first and then the labels. Labels which don't fit completely are left
+/// out.
+#[derive(Debug, Clone)]
+pub struct CompassTape<'a> {
+    heading: f64,
+    period: f64,
+    span: f64,
+    minor: f64,
+    major: f64,
+    precision: usize,
+    cardinals: bool,
+    style: Style,
+    label_style: Style,
+    marker_style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Default for CompassTape<'a> {
+    fn default() -> Self {
+        Self {
+            heading: 0.,
+            period: 360.,
+            span: 90.,
+            minor: 5.,
+            major: 30.,
+            precision: 0,
+            cardinals: false,
+            style: Style::default(),
+            label_style: Style::default(),
+            marker_style: Style::default(),
+            block: None,
+        }
+    }
+}
+
+impl<'a> CompassTape<'a> {
+    /// Set the heading shown in the center of the tape
+    pub fn heading<T>(mut self, heading: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.heading = heading.into().get();
+        self
+    }
+
+    /// After how much the axis wraps around. Defaults to `360.`, use `2π` for phases.
+    pub fn period<T>(mut self, period: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.period = period.into().get();
+        self
+    }
+
+    /// How much of the axis is visible across the whole width. Defaults to `90.`.
+    pub fn span<T>(mut self, span: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.span = span.into().get();
+        self
+    }
+
+    /// Distance between the `minor` ticks (`╷`) and the labeled `major` ones (`│`). Defaults to
+    /// `5.` and `30.`.
+    pub fn ticks<T, U>(mut self, minor: T, major: U) -> Self
+    where
+        T: Into<BarValue>,
+        U: Into<BarValue>,
+    {
+        self.minor = minor.into().get();
+        self.major = major.into().get();
+        self
+    }
+
+    /// Number of decimals of the labels. Defaults to `0`.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Label 0, 90, 180 and 270 as `N`, `E`, `S` and `W`. Only applies with a period of `360.`.
+    pub fn cardinals(mut self, cardinals: bool) -> Self {
+        self.cardinals = cardinals;
+        self
+    }
+
+    /// Apply a custom style to the tape
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style patched onto the labels
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Style patched onto the marker and the tick in the center
+    pub fn marker_style(mut self, style: Style) -> Self {
+        self.marker_style = style;
+        self
+    }
+
+    /// Surround this tape by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    fn label(&self, angle: f64) -> Cow<'static, str> {
+        let angle = angle.rem_euclid(self.period);
+        // Rounding errors may leave the angle just below the period
+        let angle = match self.period - angle < self.period * 1e-9 {
+            true => 0.,
+            false => angle,
+        };
+        let cardinal = match angle.round() as i32 {
+            _ if !self.cardinals || self.period != 360. || angle.fract() != 0. => None,
+            0 => Some("N"),
+            90 => Some("E"),
+            180 => Some("S"),
+            270 => Some("W"),
+            _ => None,
+        };
+        match cardinal {
+            Some(cardinal) => cardinal.into(),
+            None => format!("{:.*}", self.precision, angle).into(),
+        }
+    }
+}
+
+impl<'a> Widget for CompassTape<'a> {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        buffer.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        let valid = |x: f64| x.is_finite() && x > 0.;
+        if area.width < 1 || area.height < 1 || !valid(self.period) || !valid(self.span) {
+            // Not enough space to render?
+            return;
+        }
+        let (labels, ticks, marker) = match area.height {
+            1 => (None, area.top(), None),
+            2 => (Some(area.top()), area.top() + 1, None),
+            _ => (Some(area.top()), area.top() + 1, Some(area.top() + 2)),
+        };
+        let center = area.left() + area.width / 2;
+        let per_column = self.span / area.width as f64;
+        // Column of `angle`, which may lie outside of the area
+        let column = |angle: f64| {
+            let offset = wrap(angle - self.heading, self.period) / per_column;
+            center as i64 + offset.round() as i64
+        };
+        let in_area = |x: i64| (area.left() as i64..area.right() as i64).contains(&x);
+
+        for (spacing, symbol) in [(self.minor, "╷"), (self.major, "│")] {
+            if !valid(spacing) {
+                continue;
+            }
+            let first = ((self.heading - self.span / 2.) / spacing).ceil() as i64;
+            let last = ((self.heading + self.span / 2.) / spacing).floor() as i64;
+            for k in first..=last {
+                let angle = k as f64 * spacing;
+                let x = column(angle);
+                if !in_area(x) {
+                    continue;
+                }
+                buffer.get_mut(x as u16, ticks).set_symbol(symbol);
+
+                let Some(y) = labels.filter(|_| symbol == "│") else {
+                    continue;
+                };
+                let label = self.label(angle);
+                let width = label.chars().count() as i64;
+                let start = x - width / 2;
+                if !in_area(start) || !in_area(start + width - 1) {
+                    // Don't cut labels off at the edges, they would read as different numbers
+                    continue;
+                }
+                buffer.set_string(start as u16, y, label, self.label_style);
+            }
+        }
+
+        buffer.get_mut(center, ticks).set_style(self.marker_style);
+        if let Some(y) = marker {
+            let cell = buffer.get_mut(center, y);
+            cell.set_symbol("▲");
+            cell.set_style(self.marker_style);
+        }
+    }
+}
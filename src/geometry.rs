@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use ratatui::layout::{Direction, Rect};
+
+use crate::{scale::DynScale, BarValue, Linear};
+
+/// Maps values to the cells of a [ValueBar](crate::ValueBar) rendered into an area and back
+///
+/// Positions along the bar are measured in eighths of a cell, counted from its start (the left
+/// edge of a horizontal or the bottom edge of a vertical bar). The bar is rendered with the very
+/// same mapping, so both always agree.
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    area: Rect,
+    direction: Direction,
+    range: f64,
+    scale: Arc<dyn DynScale>,
+    period: Option<f64>,
+}
+
+impl PartialEq for Geometry {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area
+            && self.direction == other.direction
+            && self.range == other.range
+            && self.scale.dyn_eq(other.scale.as_ref())
+            && self.period == other.period
+    }
+}
+
+impl Geometry {
+    pub(crate) fn new(area: Rect, direction: Direction, range: f64) -> Self {
+        Self {
+            area,
+            direction,
+            range,
+            scale: Arc::new(Linear),
+            period: None,
+        }
+    }
+
+    /// A geometry only mapping the cells of `area`, for widgets which map values themselves.
+    /// Its range is zero, so it doesn't map any values.
+    pub(crate) fn without_range(area: Rect, direction: Direction) -> Self {
+        Self::new(area, direction, 0.)
+    }
+
+    /// Wrap values around by `period` into `-period/2..period/2` before mapping them. Keeps
+    /// values as they are if `period` is not finite and positive.
+    pub(crate) fn cyclic(mut self, period: Option<f64>) -> Self {
+        self.period = period.filter(|period| period.is_finite() && *period > 0.);
+        self
+    }
+
+    /// Map values to positions with `scale` instead of linearly
+    pub(crate) fn scale(mut self, scale: Arc<dyn DynScale>) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The area the bar is drawn into, i.e. inside its block
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// The upper and lower bound of the bar
+    pub fn range(&self) -> f64 {
+        self.range
+    }
+
+    /// Whether the range is finite and positive, so values can be mapped to the bar
+    pub(crate) fn has_valid_range(&self) -> bool {
+        self.range.is_finite() && self.range > 0.
+    }
+
+    /// Whether the cell `(x, y)` lies within [area](Geometry::area)
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        (self.area.left()..self.area.right()).contains(&x)
+            && (self.area.top()..self.area.bottom()).contains(&y)
+    }
+
+    /// The cell in which a bar filled up to `value` ends, as column (horizontal) or row
+    /// (vertical) in buffer coordinates, and how many eighths (`0..=8`) of that cell lie between
+    /// its start edge and the end of the bar. Values are clamped to the bar.
+    pub fn value_to_position<T>(&self, value: T) -> (u16, u8)
+    where
+        T: Into<BarValue>,
+    {
+        if self.cells() == 0 {
+            // Nothing to map to, stay at the start edge
+            let start = match self.direction {
+                Direction::Horizontal => self.area.left(),
+                Direction::Vertical => self.area.bottom(),
+            };
+            return (start, 0);
+        }
+        let end = self.end(value.into().get());
+        let index = (end / 8).min(self.cells().saturating_sub(1) as i32);
+        (self.coordinate(index as u16), (end - 8 * index) as u8)
+    }
+
+    /// The value at the cell `(x, y)` along the bar. The first and last cell map to `-range`
+    /// and `range`, the cells between them are spread evenly. Positions outside the area are
+    /// clamped to its closest cell. NaN if the range is not finite and positive.
+    pub fn position_to_value(&self, x: u16, y: u16) -> f64 {
+        if !self.has_valid_range() {
+            return f64::NAN;
+        }
+        let fraction = 2. * self.fraction(x, y) - 1.;
+        self.scale
+            .value(fraction, self.range)
+            .clamp(-self.range, self.range)
+    }
+
+    /// How far the cell `(x, y)` lies along the bar, from `0` in the first to `1` in the last
+    /// cell. Positions outside the area are clamped to its closest cell.
+    pub(crate) fn fraction(&self, x: u16, y: u16) -> f64 {
+        let cells = self.cells();
+        if cells < 2 {
+            return 0.5;
+        }
+        let coordinate = match self.direction {
+            Direction::Horizontal => x.clamp(self.area.left(), self.area.right() - 1),
+            Direction::Vertical => y.clamp(self.area.top(), self.area.bottom() - 1),
+        };
+        self.index(coordinate) as f64 / (cells - 1) as f64
+    }
+
+    /// Number of cells along the bar
+    pub(crate) fn cells(&self) -> u16 {
+        match self.direction {
+            Direction::Horizontal => self.area.width,
+            Direction::Vertical => self.area.height,
+        }
+    }
+
+    /// Index along the bar of the cell at column (horizontal) or row (vertical) `coordinate`
+    pub(crate) fn index(&self, coordinate: u16) -> u16 {
+        match self.direction {
+            Direction::Horizontal => coordinate - self.area.left(),
+            Direction::Vertical => self.area.bottom() - 1 - coordinate,
+        }
+    }
+
+    /// Column (horizontal) or row (vertical) of the cell with `index` along the bar
+    pub(crate) fn coordinate(&self, index: u16) -> u16 {
+        match self.direction {
+            Direction::Horizontal => self.area.left() + index,
+            Direction::Vertical => self.area.bottom() - 1 - index,
+        }
+    }
+
+    /// Position of zero
+    pub(crate) fn zero(&self) -> i32 {
+        4 * self.cells() as i32
+    }
+
+    /// Index of the cell containing zero
+    pub(crate) fn zero_cell(&self) -> u16 {
+        self.cells() / 2
+    }
+
+    /// `value` wrapped around by the period of a cyclic bar
+    pub(crate) fn wrapped(&self, value: f64) -> f64 {
+        match self.period {
+            Some(period) => wrap(value, period),
+            None => value,
+        }
+    }
+
+    /// Position of the end of a bar filled up to `value`, clamped to the bar
+    pub(crate) fn end(&self, value: f64) -> i32 {
+        let zero = self.zero();
+        let value = self.wrapped(value);
+        let fraction = self.scale.position(value, self.range);
+        let offset = (fraction * zero as f64).round();
+        // Clamp before converting, so infinite values don't overflow
+        zero + offset.clamp(-zero as f64, zero as f64) as i32
+    }
+
+    /// Like [fill](Geometry::fill), but for a bar filled from zero to `end` and mirrored onto the
+    /// other side, so `end` should not lie before zero. The center cell of an odd length bar is
+    /// filled completely once the bar reaches 2 eighths past zero.
+    pub(crate) fn mirrored_fill(&self, index: u16, end: i32) -> Option<(u8, bool)> {
+        let zero = self.zero();
+        let start = 8 * index as i32;
+        if start >= zero {
+            self.fill(index, end)
+        } else if start + 8 <= zero {
+            self.fill(index, 2 * zero - end)
+        } else {
+            self.fill(index, end).map(|_| (8, false))
+        }
+    }
+
+    /// How many eighths of the cell with `index` are covered by a bar ending at `end`, and
+    /// whether they are counted from the far edge of the cell (the right or top one). [None]
+    /// means the cell is not covered at all. The cell right behind the end of a positive bar is
+    /// covered by `0` eighths.
+    pub(crate) fn fill(&self, index: u16, end: i32) -> Option<(u8, bool)> {
+        let zero = self.zero();
+        let start = 8 * index as i32;
+        let stop = start + 8;
+        if end >= zero {
+            if stop <= zero {
+                None
+            } else if start >= zero {
+                let eighths = end - start;
+                (eighths >= 0).then_some((eighths.min(8) as u8, false))
+            } else {
+                // Center cell of an odd length bar, zero in its middle
+                (end - zero >= 2).then_some((4, true))
+            }
+        } else if start >= zero {
+            None
+        } else if stop <= zero {
+            let eighths = stop - end;
+            (eighths >= 1).then_some((eighths.min(8) as u8, true))
+        } else {
+            (zero - end >= 2).then_some((4, false))
+        }
+    }
+}
+
+/// `value` wrapped around by `period` into `-period/2..period/2`
+pub(crate) fn wrap(value: f64, period: f64) -> f64 {
+    value - period * (value / period + 0.5).floor()
+}
@@ -1,5 +1,29 @@
 #![doc = include_str!("../README.md")]
 
+mod barchart;
+mod compass;
+mod geometry;
+mod meter;
+mod pad;
+mod range_slider;
+mod scale;
+mod slider;
+mod sparkline;
+mod split;
+mod symbols;
+mod value;
 mod valuebar;
 
-pub use valuebar::ValueBar;
+pub use barchart::BipolarBarChart;
+pub use compass::CompassTape;
+pub use geometry::Geometry;
+pub use meter::{Ballistics, LevelMeter, LevelMeterState};
+pub use pad::{PadMarker, XyPad};
+pub use range_slider::{RangeHandle, RangeSlider, RangeSliderState};
+pub use scale::{Decibel, Linear, PiecewiseLinear, Scale, Sqrt, SymLog};
+pub use slider::{Slider, SliderAction, SliderState};
+pub use sparkline::BipolarSparkline;
+pub use split::SplitBar;
+pub use symbols::{BarSymbols, Symbols};
+pub use value::BarValue;
+pub use valuebar::{Align, NegativeFill, Thickness, ValueBar, ValueBarError};
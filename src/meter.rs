@@ -0,0 +1,317 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+use crate::{BarSymbols, BarValue, Decibel, Geometry, Scale, Symbols};
+
+/// Time constant of a VU meter, which reaches 99% of a steady tone within 300 ms
+const VU_TIME_CONSTANT: f64 = 0.3 / 4.605_17;
+/// Attack time constant of a PPM, which reaches 80% of a tone burst within 10 ms
+const PPM_ATTACK_TIME_CONSTANT: f64 = 0.01 / 1.609_438;
+/// Release of a PPM, which falls by 20 dB within 1.5 s
+const PPM_RELEASE_DB_PER_SECOND: f64 = 20. / 1.5;
+
+/// An audio level meter showing the level of a [LevelMeterState] in dBFS
+///
+/// The bar fills from its start (left or bottom edge) at the [floor](LevelMeter::floor) up to
+/// 0 dBFS at its end. A marker shows the held peak and the last cell lights up in the clip style
+/// once the signal clipped.
+#[derive(Debug, Clone)]
+pub struct LevelMeter<'a> {
+    direction: Direction,
+    floor: f64,
+    style: Style,
+    peak_style: Style,
+    clip_style: Style,
+    block: Option<Block<'a>>,
+    symbols: Arc<dyn BarSymbols + Send + Sync + 'a>,
+    track_symbol: &'a str,
+    track_style: Style,
+}
+
+/// How quickly the level of a [LevelMeterState] follows the signal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Ballistics {
+    /// Volume unit meter: follows the RMS level with a 300 ms integration time (default)
+    #[default]
+    Vu,
+    /// Peak programme meter: follows the peak level within 10 ms and falls back by 20 dB in
+    /// 1.5 s
+    Ppm,
+    /// Show the RMS level of each block as is
+    Direct,
+}
+
+/// The level of a [LevelMeter], fed with sample blocks or RMS values as they arrive
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelMeterState {
+    ballistics: Ballistics,
+    peak_hold: Duration,
+    /// Displayed level as linear amplitude, `1.` is 0 dBFS
+    level: f64,
+    peak: f64,
+    peak_time: Option<Instant>,
+    clipped: bool,
+    last: Option<Instant>,
+}
+
+impl<'a> Default for LevelMeter<'a> {
+    fn default() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            floor: -60.,
+            style: Style::default(),
+            peak_style: Style::default(),
+            clip_style: Style::default().fg(Color::Red),
+            block: None,
+            symbols: Arc::new(Symbols::default()),
+            track_symbol: " ",
+            track_style: Style::default(),
+        }
+    }
+}
+
+impl<'a> LevelMeter<'a> {
+    /// Set that this meter is filling horizontally (default) or vertically
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// The level in dBFS (negative) at the start of the meter. Defaults to `-60.`.
+    pub fn floor<T>(mut self, floor: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.floor = floor.into().get();
+        self
+    }
+
+    /// Apply a custom style to the meter
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style patched onto the peak hold marker
+    pub fn peak_style(mut self, style: Style) -> Self {
+        self.peak_style = style;
+        self
+    }
+
+    /// Style patched onto the last cell once the signal clipped. Defaults to a red foreground.
+    pub fn clip_style(mut self, style: Style) -> Self {
+        self.clip_style = style;
+        self
+    }
+
+    /// Surround this meter by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Select the glyphs used to draw the meter. Defaults to [Symbols::Eighths].
+    pub fn symbols<S>(mut self, symbols: S) -> Self
+    where
+        S: BarSymbols + Send + Sync + 'a,
+    {
+        self.symbols = Arc::new(symbols);
+        self
+    }
+
+    /// Set the symbol drawn into the unfilled cells, e.g. `░`. Defaults to `" "`.
+    pub fn track_symbol(mut self, symbol: &'a str) -> Self {
+        self.track_symbol = symbol;
+        self
+    }
+
+    /// Set the style of the unfilled cells, patched onto the meter's style
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+
+    /// Position of a linear `amplitude` in eighths from the start of a meter `cells` long
+    fn position(&self, amplitude: f64, cells: u16) -> i32 {
+        let fraction = Decibel::new(self.floor)
+            .position(amplitude, 1.)
+            .clamp(0., 1.);
+        if fraction.is_nan() {
+            return 0;
+        }
+        (fraction * 8. * cells as f64).round() as i32
+    }
+}
+
+impl<'a> StatefulWidget for LevelMeter<'a> {
+    type State = LevelMeterState;
+
+    fn render(mut self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        if area.width < 1 || area.height < 1 {
+            // Not enough space to render?
+            return;
+        }
+        let geometry = Geometry::without_range(area, self.direction);
+        let cells = geometry.cells();
+        let end = self.position(state.level, cells);
+        let peak = self.position(state.peak, cells);
+        let track = self.style.patch(self.track_style);
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let index = match self.direction {
+                    Direction::Horizontal => geometry.index(x),
+                    Direction::Vertical => geometry.index(y),
+                };
+                let start = 8 * index as i32;
+                let cell = buffer.get_mut(x, y);
+                if end > start {
+                    let eighths = (end - start).min(8) as u8;
+                    cell.set_style(self.style);
+                    cell.set_symbol(self.symbols.symbol(eighths, false, self.direction));
+                } else if peak > start && peak <= start + 8 {
+                    // The held peak ends within this cell, mark it at the far edge
+                    cell.set_style(self.style.patch(self.peak_style));
+                    cell.set_symbol(self.symbols.symbol(1, true, self.direction));
+                } else {
+                    cell.set_style(track);
+                    cell.set_symbol(self.track_symbol);
+                }
+                if state.clipped && index == cells - 1 {
+                    cell.set_style(self.clip_style);
+                    cell.set_symbol(self.symbols.symbol(8, false, self.direction));
+                }
+            }
+        }
+    }
+}
+
+impl Default for LevelMeterState {
+    fn default() -> Self {
+        Self::new(Ballistics::default())
+    }
+}
+
+impl LevelMeterState {
+    /// A silent meter with the given `ballistics`
+    pub fn new(ballistics: Ballistics) -> Self {
+        Self {
+            ballistics,
+            peak_hold: Duration::from_secs(2),
+            level: 0.,
+            peak: 0.,
+            peak_time: None,
+            clipped: false,
+            last: None,
+        }
+    }
+
+    /// How long the peak marker stays at the highest level before it follows the level again.
+    /// Defaults to 2 s.
+    pub fn peak_hold(mut self, hold: Duration) -> Self {
+        self.peak_hold = hold;
+        self
+    }
+
+    /// Feed a block of raw samples (`-1..=1` is full scale) which arrived at `time`. Samples at
+    /// or beyond full scale set the clip indicator, samples which are not finite are ignored.
+    pub fn push_samples(&mut self, samples: &[f32], time: Instant) {
+        let finite = || samples.iter().filter(|s| s.is_finite());
+        let count = finite().count();
+        if count == 0 {
+            return;
+        }
+        let peak = finite().map(|s| s.abs() as f64).fold(0., f64::max);
+        let rms = (finite().map(|s| (s * s) as f64).sum::<f64>() / count as f64).sqrt();
+        let target = match self.ballistics {
+            Ballistics::Ppm => peak,
+            Ballistics::Vu | Ballistics::Direct => rms,
+        };
+        self.update(target, time);
+        self.clipped |= peak >= 1.;
+    }
+
+    /// Feed an RMS value (`1.` is full scale) measured at `time`. Values at or beyond full scale
+    /// set the clip indicator, values which are not finite are ignored.
+    pub fn push_rms<T>(&mut self, rms: T, time: Instant)
+    where
+        T: Into<BarValue>,
+    {
+        let rms = rms.into().get();
+        if !rms.is_finite() {
+            return;
+        }
+        self.update(rms.abs(), time);
+        self.clipped |= rms.abs() >= 1.;
+    }
+
+    /// The displayed level in dBFS, `-inf` for silence
+    pub fn level_db(&self) -> f64 {
+        decibels(self.level)
+    }
+
+    /// The held peak of the displayed level in dBFS, `-inf` for silence
+    pub fn peak_db(&self) -> f64 {
+        decibels(self.peak)
+    }
+
+    /// Whether the signal reached full scale since the last [reset_clip](Self::reset_clip)
+    pub fn is_clipped(&self) -> bool {
+        self.clipped
+    }
+
+    /// Turn off the clip indicator
+    pub fn reset_clip(&mut self) {
+        self.clipped = false;
+    }
+
+    fn update(&mut self, target: f64, time: Instant) {
+        let dt = self
+            .last
+            .map(|last| time.saturating_duration_since(last).as_secs_f64());
+        self.last = Some(time);
+        let approach = |time_constant: f64, dt: f64, level: f64| {
+            level + (target - level) * (1. - (-dt / time_constant).exp())
+        };
+        self.level = match (self.ballistics, dt) {
+            (Ballistics::Direct, _) | (_, None) => target,
+            (Ballistics::Vu, Some(dt)) => approach(VU_TIME_CONSTANT, dt, self.level),
+            (Ballistics::Ppm, Some(dt)) if target >= self.level => {
+                approach(PPM_ATTACK_TIME_CONSTANT, dt, self.level)
+            }
+            (Ballistics::Ppm, Some(dt)) => {
+                let release = 10f64.powf(-PPM_RELEASE_DB_PER_SECOND * dt / 20.);
+                (self.level * release).max(target)
+            }
+        };
+
+        let held = self
+            .peak_time
+            .is_some_and(|peak_time| time.saturating_duration_since(peak_time) < self.peak_hold);
+        if self.level >= self.peak || !held {
+            self.peak = self.level;
+            self.peak_time = Some(time);
+        }
+    }
+}
+
+fn decibels(amplitude: f64) -> f64 {
+    20. * amplitude.log10()
+}
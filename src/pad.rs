@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::{Block, Widget},
+};
+
+use crate::{
+    symbols::{braille_char, braille_dot, quadrant_char},
+    BarValue,
+};
+
+/// A two-dimensional counterpart of [ValueBar](crate::ValueBar), e.g. for joysticks, 2D offsets
+/// or accelerometer readings
+///
+/// Both axes are symmetrical around zero, which is marked by a crosshair in the center. The
+/// current point is drawn as a single dot with sub-cell precision, optionally following a trail
+/// of previous points.
+#[derive(Debug, Clone)]
+pub struct XyPad<'a> {
+    point: (f64, f64),
+    range: (f64, f64),
+    trail: Vec<(f64, f64)>,
+    marker: PadMarker,
+    crosshair: bool,
+    style: Style,
+    marker_style: Style,
+    trail_style: Style,
+    crosshair_style: Style,
+    block: Option<Block<'a>>,
+}
+
+/// The dots the marker and trail of a [XyPad] are drawn with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PadMarker {
+    /// Quadrants (`▘`, `▗`, …), 2 × 2 dots per cell (default)
+    #[default]
+    Quadrants,
+    /// Braille dots (`⠁`, `⢀`, …), 2 × 4 dots per cell
+    Braille,
+}
+
+impl<'a> Default for XyPad<'a> {
+    fn default() -> Self {
+        Self {
+            point: (0., 0.),
+            range: (1., 1.),
+            trail: Vec::new(),
+            marker: PadMarker::default(),
+            crosshair: true,
+            style: Style::default(),
+            marker_style: Style::default(),
+            trail_style: Style::default(),
+            crosshair_style: Style::default(),
+            block: None,
+        }
+    }
+}
+
+impl<'a> XyPad<'a> {
+    /// Set the current point. Should be within `-range..=range` on both axes, points outside
+    /// are clamped to the edges. `y` grows upwards.
+    pub fn point<X, Y>(mut self, x: X, y: Y) -> Self
+    where
+        X: Into<BarValue>,
+        Y: Into<BarValue>,
+    {
+        self.point = (x.into().get(), y.into().get());
+        self
+    }
+
+    /// The upper and lower bound of the horizontal and vertical axis
+    pub fn range<X, Y>(mut self, x: X, y: Y) -> Self
+    where
+        X: Into<BarValue>,
+        Y: Into<BarValue>,
+    {
+        self.range = (x.into().get(), y.into().get());
+        self
+    }
+
+    /// Show previous points, oldest first, behind the current one. The older half of the trail
+    /// fades out by being dimmed. By default no trail is shown.
+    pub fn trail<I>(mut self, points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        self.trail = points.into_iter().collect();
+        self
+    }
+
+    /// Select the dots the marker and trail are drawn with. Defaults to [PadMarker::Quadrants].
+    pub fn marker(mut self, marker: PadMarker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Show the crosshair (`─`, `│`, `┼`) through zero. Enabled by default.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Apply a custom style to the pad
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style patched onto the cell of the current point
+    pub fn marker_style(mut self, style: Style) -> Self {
+        self.marker_style = style;
+        self
+    }
+
+    /// Style patched onto the cells of the trail
+    pub fn trail_style(mut self, style: Style) -> Self {
+        self.trail_style = style;
+        self
+    }
+
+    /// Style patched onto the crosshair
+    pub fn crosshair_style(mut self, style: Style) -> Self {
+        self.crosshair_style = style;
+        self
+    }
+
+    /// Surround this pad by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<'a> Widget for XyPad<'a> {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        buffer.set_style(area, self.style);
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        if area.width < 1 || area.height < 1 {
+            // Not enough space to render?
+            return;
+        }
+
+        // The cells containing zero, as in a horizontal and a vertical ValueBar
+        let center_x = area.left() + area.width / 2;
+        let center_y = area.bottom() - 1 - area.height / 2;
+        if self.crosshair {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    let symbol = match (x == center_x, y == center_y) {
+                        (true, true) => "┼",
+                        (true, false) => "│",
+                        (false, true) => "─",
+                        (false, false) => continue,
+                    };
+                    let cell = buffer.get_mut(x, y);
+                    cell.set_symbol(symbol);
+                    cell.set_style(self.crosshair_style);
+                }
+            }
+        }
+
+        let (columns, rows) = match self.marker {
+            PadMarker::Quadrants => (2, 2),
+            PadMarker::Braille => (2, 4),
+        };
+        // Dot of a point counted from the top left corner of the area
+        let dot = |(x, y): (f64, f64)| {
+            let axis = |value: f64, range: f64, dots: u16| {
+                let fraction = (value / range + 1.) / 2.;
+                (fraction * dots as f64).floor().clamp(0., dots as f64 - 1.) as u16
+            };
+            if x.is_nan() || y.is_nan() || !(self.range.0 > 0. && self.range.1 > 0.) {
+                return None;
+            }
+            let dots = (columns * area.width, rows * area.height);
+            Some((
+                axis(x, self.range.0, dots.0),
+                dots.1 - 1 - axis(y, self.range.1, dots.1),
+            ))
+        };
+
+        let faded = self.trail.len() / 2;
+        let trail = self.trail.iter().enumerate().map(|(i, point)| {
+            let style = match i < faded {
+                true => self.trail_style.add_modifier(Modifier::DIM),
+                false => self.trail_style,
+            };
+            (*point, style)
+        });
+        // Dots and style of every cell a point falls into, later points are drawn on top
+        let mut cells = BTreeMap::new();
+        for (point, style) in trail.chain([(self.point, self.marker_style)]) {
+            let Some((dx, dy)) = dot(point) else {
+                continue;
+            };
+            let (column, row) = ((dx % columns) as u8, (dy % rows) as u8);
+            let bit = match self.marker {
+                PadMarker::Quadrants => 1 << (2 * row + column),
+                PadMarker::Braille => braille_dot(row, column),
+            };
+            let cell = (area.left() + dx / columns, area.top() + dy / rows);
+            let (dots, cell_style) = cells.entry(cell).or_insert((0, style));
+            *dots |= bit;
+            *cell_style = style;
+        }
+        for ((x, y), (dots, style)) in cells {
+            let cell = buffer.get_mut(x, y);
+            cell.set_char(match self.marker {
+                PadMarker::Quadrants => quadrant_char(dots),
+                PadMarker::Braille => braille_char(dots),
+            });
+            cell.set_style(style);
+        }
+    }
+}
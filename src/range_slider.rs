@@ -0,0 +1,506 @@
+use std::sync::Arc;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::{Block, StatefulWidget, Widget},
+};
+
+use crate::{slider::Steps, BarSymbols, BarValue, Geometry, SliderAction, Symbols};
+
+/// A slider selecting an interval between two handles, e.g. for filters like
+/// "latencies between 20ms and 80ms"
+///
+/// Unlike a [ValueBar](crate::ValueBar) its bounds don't need to be symmetrical around zero. The
+/// selected span is drawn with partially filled glyphs at both ends, so each handle moves in
+/// eighths of a cell. The interval is kept in a [RangeSliderState].
+#[derive(Debug, Clone)]
+pub struct RangeSlider<'a> {
+    min: f64,
+    max: f64,
+    direction: Direction,
+    style: Style,
+    focused_style: Style,
+    handle_style: Style,
+    block: Option<Block<'a>>,
+    symbols: Arc<dyn BarSymbols + Send + Sync + 'a>,
+    track_symbol: &'a str,
+    track_style: Style,
+    readout: Option<usize>,
+    steps: Steps,
+}
+
+/// The selected interval of a [RangeSlider] and what's needed to handle input for it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RangeSliderState {
+    low: f64,
+    high: f64,
+    bounds: Option<(f64, f64)>,
+    geometry: Option<Geometry>,
+    steps: Steps,
+    active: RangeHandle,
+    dragging: Option<RangeHandle>,
+    focused: bool,
+}
+
+/// One of the two handles of a [RangeSlider]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RangeHandle {
+    /// The lower end of the interval (default)
+    #[default]
+    Low,
+    /// The upper end of the interval
+    High,
+}
+
+impl<'a> Default for RangeSlider<'a> {
+    fn default() -> Self {
+        Self {
+            min: 0.,
+            max: 1.,
+            direction: Direction::Horizontal,
+            style: Style::default(),
+            focused_style: Style::default(),
+            handle_style: Style::default(),
+            block: None,
+            symbols: Arc::new(Symbols::default()),
+            track_symbol: " ",
+            track_style: Style::default(),
+            readout: None,
+            steps: Steps::default(),
+        }
+    }
+}
+
+impl<'a> RangeSlider<'a> {
+    /// A slider selecting an interval within `min..=max`. Unless both are finite and `min` is
+    /// less than `max`, the slider is empty and ignores input.
+    pub fn new<T, U>(min: T, max: U) -> Self
+    where
+        T: Into<BarValue>,
+        U: Into<BarValue>,
+    {
+        Self {
+            min: min.into().get(),
+            max: max.into().get(),
+            ..Self::default()
+        }
+    }
+
+    /// Set that this slider is filling horizontally (default) or vertically
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Apply a custom style to the slider
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style patched onto the selected span while the slider is focused
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Style patched onto the cell of the [active](RangeSliderState::active) handle while the
+    /// slider is focused, so users can tell which handle the keys move
+    pub fn handle_style(mut self, style: Style) -> Self {
+        self.handle_style = style;
+        self
+    }
+
+    /// Surround this slider by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Select the glyphs used to draw the span. Defaults to [Symbols::Eighths].
+    pub fn symbols<S>(mut self, symbols: S) -> Self
+    where
+        S: BarSymbols + Send + Sync + 'a,
+    {
+        self.symbols = Arc::new(symbols);
+        self
+    }
+
+    /// Set the symbol drawn into the cells outside the span, e.g. `░`. Defaults to `" "`.
+    pub fn track_symbol(mut self, symbol: &'a str) -> Self {
+        self.track_symbol = symbol;
+        self
+    }
+
+    /// Set the style of the cells outside the span, patched onto the slider's style
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+
+    /// Show the selected interval as `low – high` with `precision` decimals in the middle of the
+    /// slider. If the slider is too small, the readout won't be rendered.
+    pub fn readout(mut self, precision: usize) -> Self {
+        self.readout = Some(precision);
+        self
+    }
+
+    /// Quantize both handles to `min` plus multiples of `step`, which is also the amount
+    /// [SliderAction::Increase] and [SliderAction::Decrease] move a handle by. By default
+    /// handles are only limited by the resolution of the cells and keys move by one cell.
+    pub fn step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.step = Some(step.into().get());
+        self
+    }
+
+    /// The amount [SliderAction::PageIncrease] and [SliderAction::PageDecrease] move a handle
+    /// by. Defaults to ten steps.
+    pub fn page_step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.page = Some(step.into().get());
+        self
+    }
+
+    /// The amount [SliderAction::FineIncrease] and [SliderAction::FineDecrease] move a handle
+    /// by, bypassing the quantization to [step](RangeSlider::step). Defaults to a tenth of a step.
+    pub fn fine_step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.fine = Some(step.into().get());
+        self
+    }
+}
+
+impl<'a> StatefulWidget for RangeSlider<'a> {
+    type State = RangeSliderState;
+
+    fn render(mut self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        let geometry = Geometry::without_range(area, self.direction);
+        state.geometry = Some(geometry.clone());
+        // Without valid bounds there is nothing to select, input is ignored
+        let valid = self.min.is_finite() && self.max.is_finite() && self.min < self.max;
+        state.bounds = valid.then_some((self.min, self.max));
+        state.steps = self.steps;
+        if valid {
+            // Only clamp, fine steps may have left the handles between steps
+            state.high = state.high.clamp(self.min, self.max);
+            state.low = state.low.clamp(self.min, state.high);
+        }
+
+        let length = geometry.cells();
+        if area.width < 1 || area.height < 1 || !valid {
+            // Not enough space to render?
+            return;
+        }
+
+        let total = 8 * length as i32;
+        let position = |value: f64| {
+            let fraction = (value - self.min) / (self.max - self.min);
+            ((fraction * total as f64).round() as i32).clamp(0, total)
+        };
+        let (start, end) = (position(state.low), position(state.high));
+        let handle = match state.active {
+            RangeHandle::Low => start / 8,
+            RangeHandle::High => (end - 1).max(start) / 8,
+        }
+        .min(length as i32 - 1);
+        let style = self.style.patch(match state.focused {
+            true => self.focused_style,
+            false => Style::default(),
+        });
+        let track = style.patch(self.track_style);
+
+        let readout = self
+            .readout
+            .map(|precision| format!("{:.*} – {:.*}", precision, state.low, precision, state.high));
+        let readout = readout.filter(|text| text.chars().count() as u16 <= area.width);
+        let readout_row = area.top() + (area.height - 1) / 2;
+        let readout_start = readout.as_ref().map_or(0, |text| {
+            area.left() + (area.width - text.chars().count() as u16) / 2
+        });
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let index = match self.direction {
+                    Direction::Horizontal => geometry.index(x),
+                    Direction::Vertical => geometry.index(y),
+                };
+                let (s, e) = (8 * index as i32, 8 * index as i32 + 8);
+                let symbol = if end < s || start >= e {
+                    None
+                } else if start <= s && end >= e {
+                    Some(self.symbols.symbol(8, false, self.direction))
+                } else if start <= s {
+                    Some(self.symbols.symbol((end - s) as u8, false, self.direction))
+                } else if end >= e || start + end > s + e {
+                    // Both ends within one cell: anchor the glyph at the edge closer to the span
+                    Some(self.symbols.symbol((e - start) as u8, true, self.direction))
+                } else {
+                    Some(self.symbols.symbol((end - s) as u8, false, self.direction))
+                };
+
+                let cell = buffer.get_mut(x, y);
+                match symbol {
+                    Some(symbol) => {
+                        cell.set_style(style);
+                        cell.set_symbol(symbol);
+                    }
+                    None => {
+                        cell.set_style(track);
+                        cell.set_symbol(self.track_symbol);
+                    }
+                }
+                if state.focused && index as i32 == handle {
+                    cell.set_style(self.handle_style);
+                }
+
+                if y != readout_row {
+                    continue;
+                }
+                let c = x
+                    .checked_sub(readout_start)
+                    .and_then(|x| readout.as_ref()?.chars().nth(x as usize));
+                if let Some(c) = c {
+                    cell.set_char(c);
+                    cell.set_style(match symbol {
+                        Some("█") => Style::default()
+                            .fg(Color::Reset)
+                            .bg(style.fg.unwrap_or(Color::Reset)),
+                        Some(_) => style,
+                        None => track,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl RangeSliderState {
+    /// A state selecting `low..=high`
+    pub fn new<T, U>(low: T, high: U) -> Self
+    where
+        T: Into<BarValue>,
+        U: Into<BarValue>,
+    {
+        let (low, high) = (low.into().get(), high.into().get());
+        Self {
+            low: low.min(high),
+            high: high.max(low),
+            ..Self::default()
+        }
+    }
+
+    /// The lower end of the selected interval
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    /// The upper end of the selected interval
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    /// Move the lower end, clamped between the lower bound and the upper end and quantized to
+    /// the step of the last render
+    pub fn set_low<T>(&mut self, value: T)
+    where
+        T: Into<BarValue>,
+    {
+        self.set(RangeHandle::Low, value.into().get(), true);
+    }
+
+    /// Move the upper end, clamped between the lower end and the upper bound and quantized to
+    /// the step of the last render
+    pub fn set_high<T>(&mut self, value: T)
+    where
+        T: Into<BarValue>,
+    {
+        self.set(RangeHandle::High, value.into().get(), true);
+    }
+
+    /// The handle moved by keyboard actions
+    pub fn active(&self) -> RangeHandle {
+        self.active
+    }
+
+    /// Select the handle moved by keyboard actions
+    pub fn select(&mut self, handle: RangeHandle) {
+        self.active = handle;
+    }
+
+    /// Whether the slider is focused and drawn with its focused style
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Set whether the slider is focused
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Move the [active](RangeSliderState::active) handle by a keyboard `action`. A handle never
+    /// passes the other one: [SliderAction::Min] and [SliderAction::Max] move it as far as
+    /// possible, [SliderAction::Zero] as close to zero as possible. Has no effect before the
+    /// slider was rendered. Returns whether the interval changed.
+    pub fn apply(&mut self, action: SliderAction) -> bool {
+        let (Some(geometry), Some((min, max))) = (&self.geometry, self.bounds) else {
+            return false;
+        };
+        let (step, page, fine) = self.steps.resolve(max - min, geometry.cells());
+        let handle = self.active;
+        let value = self.value(handle);
+        match action {
+            SliderAction::Increase => self.set(handle, value + step, true),
+            SliderAction::Decrease => self.set(handle, value - step, true),
+            SliderAction::PageIncrease => self.set(handle, value + page, true),
+            SliderAction::PageDecrease => self.set(handle, value - page, true),
+            SliderAction::FineIncrease => self.set(handle, value + fine, false),
+            SliderAction::FineDecrease => self.set(handle, value - fine, false),
+            SliderAction::Min => self.set(handle, f64::NEG_INFINITY, false),
+            SliderAction::Max => self.set(handle, f64::INFINITY, false),
+            SliderAction::Zero => self.set(handle, 0., true),
+        }
+    }
+
+    /// Adjust the interval from a crossterm key event: Tab and BackTab switch the active handle,
+    /// all other keys are mapped by [SliderAction::from_key_event]. Returns whether the event was
+    /// handled.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_key_event(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::{KeyCode, KeyEventKind};
+        match event.code {
+            KeyCode::Tab | KeyCode::BackTab if event.kind != KeyEventKind::Release => {
+                self.active = match self.active {
+                    RangeHandle::Low => RangeHandle::High,
+                    RangeHandle::High => RangeHandle::Low,
+                };
+                true
+            }
+            _ => SliderAction::from_key_event(event).is_some_and(|action| self.apply(action)),
+        }
+    }
+
+    /// Whether a drag started by [press](RangeSliderState::press) is in progress
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+
+    /// Handle a mouse button pressed on cell `(x, y)`. If it hits the slider, the closer handle
+    /// becomes active, jumps to that position and a drag starts. Returns whether the interval
+    /// changed.
+    pub fn press(&mut self, x: u16, y: u16) -> bool {
+        let Some(geometry) = self
+            .geometry
+            .clone()
+            .filter(|geometry| geometry.contains(x, y))
+        else {
+            return false;
+        };
+        let Some(value) = self.position_to_value(&geometry, x, y) else {
+            return false;
+        };
+        let handle = if value <= self.low {
+            RangeHandle::Low
+        } else if value >= self.high || value - self.low > self.high - value {
+            RangeHandle::High
+        } else {
+            RangeHandle::Low
+        };
+        self.active = handle;
+        self.dragging = Some(handle);
+        self.set(handle, value, true)
+    }
+
+    /// Handle the mouse being moved to cell `(x, y)` with the button held. Only has an effect
+    /// while dragging, the dragged handle stops at the other one. Returns whether the interval
+    /// changed.
+    pub fn drag(&mut self, x: u16, y: u16) -> bool {
+        match (self.geometry.clone(), self.dragging) {
+            (Some(geometry), Some(handle)) => self
+                .position_to_value(&geometry, x, y)
+                .is_some_and(|value| self.set(handle, value, true)),
+            _ => false,
+        }
+    }
+
+    /// Handle the mouse button being released, ending a drag
+    pub fn release(&mut self) {
+        self.dragging = None;
+    }
+
+    /// Update the interval from a crossterm mouse event: a left click moves the closer handle to
+    /// the clicked position, dragging moves it further. Returns whether the interval changed.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_mouse_event(&mut self, event: crossterm::event::MouseEvent) -> bool {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.press(event.column, event.row),
+            MouseEventKind::Drag(MouseButton::Left) => self.drag(event.column, event.row),
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.release();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn value(&self, handle: RangeHandle) -> f64 {
+        match handle {
+            RangeHandle::Low => self.low,
+            RangeHandle::High => self.high,
+        }
+    }
+
+    /// Move `handle` to `value`, keeping it within the bounds and off the other handle. NaN is
+    /// ignored.
+    fn set(&mut self, handle: RangeHandle, value: f64, quantize: bool) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+        let (min, max) = self.bounds.unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+        let value = match quantize {
+            true => self.quantize(value),
+            false => value,
+        };
+        let before = (self.low, self.high);
+        match handle {
+            RangeHandle::Low => self.low = value.clamp(min, self.high),
+            RangeHandle::High => self.high = value.clamp(self.low, max),
+        }
+        (self.low, self.high) != before
+    }
+
+    /// Round `value` to the lower bound plus a multiple of the step of the last render
+    fn quantize(&self, value: f64) -> f64 {
+        match (self.steps.step, self.bounds) {
+            (Some(step), Some((min, _))) if step > 0. && value.is_finite() => {
+                min + ((value - min) / step).round() * step
+            }
+            _ => value,
+        }
+    }
+
+    fn position_to_value(&self, geometry: &Geometry, x: u16, y: u16) -> Option<f64> {
+        let (min, max) = self.bounds?;
+        if geometry.cells() == 0 {
+            return None;
+        }
+        Some(min + geometry.fraction(x, y) * (max - min))
+    }
+}
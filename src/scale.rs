@@ -0,0 +1,199 @@
+use std::{any::Any, fmt};
+
+/// Maps values to positions along a bar and back
+///
+/// Positions are fractions of the way from zero (`0`) to the end of the bar at `range` (`1`) or
+/// `-range` (`-1`). Positions beyond that are clamped to the bar. Implement this for custom
+/// transfer functions, the built-in ones are [Linear] (default), [SymLog], [Sqrt], [Decibel] and
+/// [PiecewiseLinear].
+pub trait Scale: fmt::Debug {
+    /// The position of `value` on a bar spanning `-range..=range`
+    fn position(&self, value: f64, range: f64) -> f64;
+
+    /// The value at `position`, the inverse of [position](Scale::position)
+    fn value(&self, position: f64, range: f64) -> f64;
+}
+
+/// A [Scale] which can be shared between threads and compared to scales of any type
+pub(crate) trait DynScale: Scale + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    /// Whether `other` is of the same type and equal to this scale
+    fn dyn_eq(&self, other: &dyn DynScale) -> bool;
+}
+
+impl<S> DynScale for S
+where
+    S: Scale + PartialEq + Send + Sync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynScale) -> bool {
+        other.as_any().downcast_ref::<S>() == Some(self)
+    }
+}
+
+/// Positions proportional to the value
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Linear;
+
+impl Scale for Linear {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        value / range
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        position * range
+    }
+}
+
+/// A symmetric log scale, `sign(v) * ln(1 + |v| / linear_region)`, for values spanning several
+/// orders of magnitude
+///
+/// Values well within `linear_region` around zero are mapped about linearly. If
+/// `linear_region` is not finite and positive, the scale is [Linear].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SymLog {
+    linear_region: f64,
+}
+
+impl SymLog {
+    /// A log scale which is about linear within `linear_region` around zero
+    pub fn new(linear_region: f64) -> Self {
+        Self { linear_region }
+    }
+
+    fn is_linear(&self) -> bool {
+        !(self.linear_region.is_finite() && self.linear_region > 0.)
+    }
+}
+
+impl Scale for SymLog {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        if self.is_linear() {
+            return Linear.position(value, range);
+        }
+        let c = self.linear_region;
+        value.signum() * (value.abs() / c).ln_1p() / (range / c).ln_1p()
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        if self.is_linear() {
+            return Linear.value(position, range);
+        }
+        let c = self.linear_region;
+        position.signum() * c * (position.abs() * (range / c).ln_1p()).exp_m1()
+    }
+}
+
+/// Positions proportional to the square root of the value, e.g. to show power as amplitude
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Sqrt;
+
+impl Scale for Sqrt {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        value.signum() * (value.abs() / range).sqrt()
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        position.signum() * position * position * range
+    }
+}
+
+/// Positions proportional to the level of an amplitude in decibels relative to `range`, e.g. for
+/// audio
+///
+/// `range` sits at 0 dB at the end of the bar, amplitudes at or below `floor` (e.g. `-60.`)
+/// don't fill the bar at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decibel {
+    floor: f64,
+}
+
+impl Decibel {
+    /// A decibel scale showing levels from `floor` (negative, in dB) up to 0 dB
+    pub fn new(floor: f64) -> Self {
+        Self { floor }
+    }
+}
+
+impl Default for Decibel {
+    fn default() -> Self {
+        Self::new(-60.)
+    }
+}
+
+impl Scale for Decibel {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        let level = 20. * (value.abs() / range).log10();
+        value.signum() * (1. - level / self.floor).max(0.)
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        if position == 0. {
+            return 0.;
+        }
+        let level = (1. - position.abs()) * self.floor;
+        position.signum() * range * 10f64.powf(level / 20.)
+    }
+}
+
+/// Positions interpolated linearly between calibration points, e.g. for sensors
+///
+/// Each point maps a value to a position (`-1..=1`). Values outside of the points are clamped to
+/// the first or last one. With less than two points the scale is [Linear].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PiecewiseLinear {
+    /// The points sorted by value
+    points: Vec<(f64, f64)>,
+    /// The points swapped to `(position, value)` and sorted by position
+    inverse: Vec<(f64, f64)>,
+}
+
+impl PiecewiseLinear {
+    /// A scale through `points` of `(value, position)`, which should increase in both
+    pub fn new<I>(points: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let mut points: Vec<_> = points.into_iter().collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut inverse: Vec<_> = points.iter().map(|(v, p)| (*p, *v)).collect();
+        inverse.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points, inverse }
+    }
+}
+
+impl Scale for PiecewiseLinear {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        match self.points.len() {
+            0 | 1 => Linear.position(value, range),
+            _ => interpolate(&self.points, value),
+        }
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        match self.inverse.len() {
+            0 | 1 => Linear.value(position, range),
+            _ => interpolate(&self.inverse, position),
+        }
+    }
+}
+
+/// Interpolate `x` on the polyline through at least two `points`, which are sorted by `x`
+fn interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    if x.is_nan() {
+        x
+    } else if x <= first.0 {
+        first.1
+    } else if x >= last.0 {
+        last.1
+    } else {
+        let i = points.partition_point(|(px, _)| *px < x);
+        let (a, b) = (points[i - 1], points[i]);
+        a.1 + (x - a.0) / (b.0 - a.0) * (b.1 - a.1)
+    }
+}
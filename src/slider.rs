@@ -0,0 +1,320 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::{BarValue, Geometry, ValueBar};
+
+/// A [ValueBar] whose value can be adjusted with the mouse or keyboard
+///
+/// The value is kept in a [SliderState], which also remembers where the slider was rendered
+/// last, so mouse events can be mapped back to values.
+#[derive(Debug, Clone, Default)]
+pub struct Slider<'a> {
+    bar: ValueBar<'a>,
+    steps: Steps,
+    style: Style,
+    focused_style: Style,
+}
+
+/// The value of a [Slider] and what's needed to handle input for it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SliderState {
+    value: f64,
+    geometry: Option<Geometry>,
+    steps: Steps,
+    dragging: bool,
+    focused: bool,
+}
+
+/// Step sizes of a [Slider], copied into its state on render
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Steps {
+    pub(crate) step: Option<f64>,
+    pub(crate) page: Option<f64>,
+    pub(crate) fine: Option<f64>,
+}
+
+/// A backend independent input adjusting a [Slider]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SliderAction {
+    /// Increase by one step, e.g. with the right or up arrow key
+    Increase,
+    /// Decrease by one step, e.g. with the left or down arrow key
+    Decrease,
+    /// Increase by one fine step, e.g. with an arrow key and a modifier held
+    FineIncrease,
+    /// Decrease by one fine step, e.g. with an arrow key and a modifier held
+    FineDecrease,
+    /// Increase by one page step, e.g. with PageUp
+    PageIncrease,
+    /// Decrease by one page step, e.g. with PageDown
+    PageDecrease,
+    /// Jump to `-range`, e.g. with Home
+    Min,
+    /// Jump to `range`, e.g. with End
+    Max,
+    /// Jump to zero
+    Zero,
+}
+
+impl<'a> Slider<'a> {
+    /// Draw the slider with `bar`. The bar's value is replaced by the one of the [SliderState].
+    pub fn new(bar: ValueBar<'a>) -> Self {
+        Self {
+            bar,
+            ..Self::default()
+        }
+    }
+
+    /// Quantize values to multiples of `step`, which is also the amount
+    /// [SliderAction::Increase] and [SliderAction::Decrease] change the value by. By default
+    /// values are only limited by the resolution of the cells and keys move by one cell.
+    pub fn step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.step = Some(step.into().get());
+        self
+    }
+
+    /// The amount [SliderAction::PageIncrease] and [SliderAction::PageDecrease] change the value
+    /// by. Defaults to ten steps.
+    pub fn page_step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.page = Some(step.into().get());
+        self
+    }
+
+    /// The amount [SliderAction::FineIncrease] and [SliderAction::FineDecrease] change the value
+    /// by, bypassing the quantization to [step](Slider::step). Defaults to a tenth of a step.
+    pub fn fine_step<T>(mut self, step: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.steps.fine = Some(step.into().get());
+        self
+    }
+
+    /// Style patched onto the bar while the slider is not focused
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style patched onto the bar while the slider is focused
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+}
+
+impl<'a> StatefulWidget for Slider<'a> {
+    type State = SliderState;
+
+    fn render(self, area: Rect, buffer: &mut Buffer, state: &mut Self::State) {
+        let geometry = self.bar.geometry(area);
+        state.geometry = Some(geometry.clone());
+        state.steps = self.steps;
+        // Only clamp, fine steps may have left the value between steps
+        state.value = state.clamp(state.value);
+        let style = match state.focused {
+            true => self.focused_style,
+            false => self.style,
+        };
+        self.bar
+            .value(state.value)
+            .patch_style(style)
+            .render(area, buffer);
+    }
+}
+
+impl SliderState {
+    /// A state starting at `value`
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        Self {
+            value: value.into().get(),
+            ..Self::default()
+        }
+    }
+
+    /// The current value
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Set the value, clamped to the range and quantized to the step of the last render. NaN is
+    /// ignored.
+    pub fn set_value<T>(&mut self, value: T)
+    where
+        T: Into<BarValue>,
+    {
+        let value = value.into().get();
+        if value.is_nan() {
+            return;
+        }
+        self.value = self.clamp(self.quantize(value));
+    }
+
+    /// Whether the slider is focused and drawn with its focused style
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Set whether the slider is focused
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Adjust the value by a keyboard `action`. Has no effect before the slider was rendered or
+    /// if its range is invalid. Returns whether the value changed.
+    pub fn apply(&mut self, action: SliderAction) -> bool {
+        let Some(geometry) = self.geometry.as_ref().filter(|g| g.has_valid_range()) else {
+            return false;
+        };
+        let range = geometry.range();
+        let (step, page, fine) = self.steps.resolve(2. * range, geometry.cells());
+
+        let before = self.value;
+        match action {
+            SliderAction::Increase => self.set_value(self.value + step),
+            SliderAction::Decrease => self.set_value(self.value - step),
+            SliderAction::PageIncrease => self.set_value(self.value + page),
+            SliderAction::PageDecrease => self.set_value(self.value - page),
+            SliderAction::FineIncrease => self.value = self.clamp(self.value + fine),
+            SliderAction::FineDecrease => self.value = self.clamp(self.value - fine),
+            SliderAction::Min => self.set_value(-range),
+            SliderAction::Max => self.set_value(range),
+            SliderAction::Zero => self.set_value(0.),
+        }
+        self.value != before
+    }
+
+    /// Adjust the value from a crossterm key event, see [SliderAction::from_key_event].
+    /// Returns whether the value changed.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_key_event(&mut self, event: crossterm::event::KeyEvent) -> bool {
+        SliderAction::from_key_event(event).is_some_and(|action| self.apply(action))
+    }
+
+    /// Whether a drag started by [press](SliderState::press) is in progress
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Handle a mouse button pressed on cell `(x, y)`. If it hits the slider, the value jumps to
+    /// that position and a drag starts. Returns whether the value changed.
+    pub fn press(&mut self, x: u16, y: u16) -> bool {
+        match self.geometry.clone() {
+            Some(geometry) if geometry.contains(x, y) => {
+                self.dragging = true;
+                self.jump(&geometry, x, y)
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle the mouse being moved to cell `(x, y)` with the button held. Only has an effect
+    /// while dragging, positions outside the slider are clamped to it. Returns whether the value
+    /// changed.
+    pub fn drag(&mut self, x: u16, y: u16) -> bool {
+        match self.geometry.clone() {
+            // An empty slider, e.g. after the terminal shrank, has no position to drag to
+            Some(geometry) if self.dragging && geometry.cells() > 0 => self.jump(&geometry, x, y),
+            _ => false,
+        }
+    }
+
+    /// Handle the mouse button being released, ending a drag
+    pub fn release(&mut self) {
+        self.dragging = false;
+    }
+
+    /// Update the value from a crossterm mouse event: a left click jumps to the clicked position,
+    /// dragging moves it. Returns whether the value changed.
+    #[cfg(feature = "crossterm")]
+    pub fn handle_mouse_event(&mut self, event: crossterm::event::MouseEvent) -> bool {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.press(event.column, event.row),
+            MouseEventKind::Drag(MouseButton::Left) => self.drag(event.column, event.row),
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.release();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn jump(&mut self, geometry: &Geometry, x: u16, y: u16) -> bool {
+        let value = geometry.position_to_value(x, y);
+        if value.is_nan() {
+            return false;
+        }
+        let before = self.value;
+        self.set_value(value);
+        self.value != before
+    }
+
+    /// Round `value` to a multiple of the step of the last render
+    fn quantize(&self, value: f64) -> f64 {
+        match self.steps.step {
+            Some(step) if step > 0. => (value / step).round() * step,
+            _ => value,
+        }
+    }
+
+    /// Clamp `value` to the range of the last render, if it is valid
+    fn clamp(&self, value: f64) -> f64 {
+        match &self.geometry {
+            Some(geometry) if geometry.has_valid_range() => {
+                let range = geometry.range();
+                value.clamp(-range, range)
+            }
+            _ => value,
+        }
+    }
+}
+
+impl Steps {
+    /// Step, page step and fine step for a slider spanning `span` over `cells`, falling back to
+    /// one cell, ten steps and a tenth of a step
+    pub(crate) fn resolve(&self, span: f64, cells: u16) -> (f64, f64, f64) {
+        let step = self.step.unwrap_or(span / cells.max(1) as f64);
+        let page = self.page.unwrap_or(10. * step);
+        let fine = self.fine.unwrap_or(step / 10.);
+        (step, page, fine)
+    }
+}
+
+impl SliderAction {
+    /// Map arrow keys, PageUp/PageDown, Home/End and `0` to an action. Arrow keys with Shift
+    /// held adjust finely.
+    #[cfg(feature = "crossterm")]
+    pub fn from_key_event(event: crossterm::event::KeyEvent) -> Option<Self> {
+        use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
+        if event.kind == KeyEventKind::Release {
+            return None;
+        }
+        let fine = event.modifiers.contains(KeyModifiers::SHIFT);
+        Some(match event.code {
+            KeyCode::Right | KeyCode::Up if fine => SliderAction::FineIncrease,
+            KeyCode::Left | KeyCode::Down if fine => SliderAction::FineDecrease,
+            KeyCode::Right | KeyCode::Up => SliderAction::Increase,
+            KeyCode::Left | KeyCode::Down => SliderAction::Decrease,
+            KeyCode::PageUp => SliderAction::PageIncrease,
+            KeyCode::PageDown => SliderAction::PageDecrease,
+            KeyCode::Home => SliderAction::Min,
+            KeyCode::End => SliderAction::Max,
+            KeyCode::Char('0') => SliderAction::Zero,
+            _ => return None,
+        })
+    }
+}
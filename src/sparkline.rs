@@ -0,0 +1,150 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::Style,
+    widgets::{Block, Widget},
+};
+
+use crate::{
+    symbols::{braille_char, braille_column, braille_dot},
+    Geometry, Symbols, ValueBar,
+};
+
+/// A rolling history of signed samples, drawn as one-cell-wide bars around a zero line
+///
+/// The newest sample is drawn in the rightmost column. If there are more samples than columns,
+/// the oldest ones are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct BipolarSparkline<'a> {
+    data: Vec<f32>,
+    range: Option<f32>,
+    style: Style,
+    block: Option<Block<'a>>,
+    symbols: Symbols,
+}
+
+impl<'a> BipolarSparkline<'a> {
+    /// Set the samples to show, oldest first. Works with slices as well as ring buffers, e.g.
+    /// `VecDeque::iter().copied()`.
+    pub fn data<I>(mut self, data: I) -> Self
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        self.data = data.into_iter().collect();
+        self
+    }
+
+    /// The upper and lower bound of every bar.
+    /// If not set, the largest absolute value of the visible samples is used.
+    pub fn range(mut self, range: f32) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Apply a custom style to the bars
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Select the glyphs used to draw the bars. With [Symbols::Braille] two samples are packed
+    /// into every cell.
+    pub fn symbols(mut self, symbols: Symbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Surround this sparkline by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl<'a> Widget for BipolarSparkline<'a> {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        if area.width < 1 || area.height < 1 {
+            // Not enough space to render?
+            return;
+        }
+
+        let samples_per_cell = match self.symbols {
+            Symbols::Braille => 2,
+            _ => 1,
+        };
+        let capacity = area.width as usize * samples_per_cell;
+        let visible = &self.data[self.data.len().saturating_sub(capacity)..];
+        let range = self
+            .range
+            .unwrap_or_else(|| visible.iter().map(|x| x.abs()).fold(0., f32::max));
+        let range = if range > 0. { range } else { 1. };
+
+        if let Symbols::Braille = self.symbols {
+            render_braille(visible, range, area, self.style, buffer);
+            return;
+        }
+
+        let offset = area.width - visible.len() as u16;
+        for (i, value) in visible.iter().enumerate() {
+            let column = Rect {
+                x: area.left() + offset + i as u16,
+                width: 1,
+                ..area
+            };
+            ValueBar::default()
+                .value(*value)
+                .range(range)
+                .direction(Direction::Vertical)
+                .style(self.style)
+                .symbols(self.symbols)
+                .render(column, buffer);
+        }
+    }
+}
+
+/// Samples are drawn as dot columns. A NaN sample has no data and is drawn as a dotted column,
+/// a cell of which all samples are NaN as `╎` like a [ValueBar] without data.
+fn render_braille(samples: &[f32], range: f32, area: Rect, style: Style, buffer: &mut Buffer) {
+    let geometry = Geometry::new(area, Direction::Vertical, range as f64);
+    let ends: Vec<Option<i32>> = samples
+        .iter()
+        .map(|value| (!value.is_nan()).then(|| geometry.end(*value as f64)))
+        .collect();
+    // Index of the dot column, counted from the left edge of the area, of the first sample
+    let offset = 2 * area.width as usize - samples.len();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell_samples: Vec<_> = (0..2)
+                .filter_map(|column| {
+                    let i = (2 * (x - area.left()) as usize + column).checked_sub(offset)?;
+                    Some((column as u8, ends[i]))
+                })
+                .collect();
+            let cell = buffer.get_mut(x, y);
+            cell.set_style(style);
+            if !cell_samples.is_empty() && cell_samples.iter().all(|(_, end)| end.is_none()) {
+                cell.set_symbol("╎");
+                continue;
+            }
+            let dots = cell_samples
+                .into_iter()
+                .filter_map(|(column, end)| match end {
+                    Some(end) => {
+                        let (eighths, far) = geometry.fill(geometry.index(y), end)?;
+                        Some(braille_column(eighths, far, column))
+                    }
+                    None => Some(braille_dot(0, column) | braille_dot(2, column)),
+                })
+                .fold(0, |dots, column| dots | column);
+            cell.set_char(braille_char(dots));
+        }
+    }
+}
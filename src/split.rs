@@ -0,0 +1,268 @@
+use std::{borrow::Cow, sync::Arc};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::Style,
+    widgets::{Block, Widget},
+};
+
+use crate::{symbols::quadrant_char, BarSymbols, BarValue, Geometry, Symbols, ValueBar};
+
+/// One bar showing two related values, e.g. rx/tx, read/write or left/right channels
+///
+/// The bar is split in half across its direction: the first value fills the top (horizontal) or
+/// left (vertical) half, the second one the other half, each as a [ValueBar] with its own label
+/// and style. If the area is an odd number of cells thick, the two values share the middle row
+/// or column, drawn with quadrants (`▀`/`▄`, `▌`/`▐`, `▘`, …) in the style of each value, which
+/// gives both values 2 steps per cell there. A cell can only show two colors, so where both
+/// values end within the same shared cell, only the first one is drawn in it. A single row or
+/// column shows both labels next to each other around zero.
+#[derive(Debug, Clone)]
+pub struct SplitBar<'a> {
+    values: (f64, f64),
+    range: f64,
+    labels: (Cow<'a, str>, Cow<'a, str>),
+    styles: (Style, Style),
+    direction: Direction,
+    symbols: Arc<dyn BarSymbols + Send + Sync + 'a>,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> Default for SplitBar<'a> {
+    fn default() -> Self {
+        Self {
+            values: (0., 0.),
+            range: 1.,
+            labels: ("".into(), "".into()),
+            styles: (Style::default(), Style::default()),
+            direction: Direction::Horizontal,
+            symbols: Arc::new(Symbols::default()),
+            block: None,
+        }
+    }
+}
+
+impl<'a> SplitBar<'a> {
+    /// Set the `first` and `second` value. Should be between `-range` and `range`.
+    pub fn values<A, B>(mut self, first: A, second: B) -> Self
+    where
+        A: Into<BarValue>,
+        B: Into<BarValue>,
+    {
+        self.values = (first.into().get(), second.into().get());
+        self
+    }
+
+    /// The upper and lower bound shared by both values
+    pub fn range<T>(mut self, range: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.range = range.into().get();
+        self
+    }
+
+    /// Show a label at the zero position of each half. If a half is too small, its label won't
+    /// be rendered.
+    pub fn labels<A, B>(mut self, first: A, second: B) -> Self
+    where
+        A: Into<Cow<'a, str>>,
+        B: Into<Cow<'a, str>>,
+    {
+        self.labels = (first.into(), second.into());
+        self
+    }
+
+    /// Apply a custom style to the `first` and `second` value, e.g. two colors
+    pub fn styles(mut self, first: Style, second: Style) -> Self {
+        self.styles = (first, second);
+        self
+    }
+
+    /// Set that this bar is filling horizontally (default) or vertically
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Select the glyphs used to draw both halves, either one of the built-in [Symbols] or a
+    /// custom [BarSymbols] implementation. Defaults to [Symbols::Eighths].
+    pub fn symbols<S>(mut self, symbols: S) -> Self
+    where
+        S: BarSymbols + Send + Sync + 'a,
+    {
+        self.symbols = Arc::new(symbols);
+        self
+    }
+
+    /// Surround this bar by a [Block]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    fn bar(&self, value: f64, label: Cow<'a, str>, style: Style) -> ValueBar<'a> {
+        ValueBar::default()
+            .value(value)
+            .range(self.range)
+            .label(label)
+            .style(style)
+            .direction(self.direction)
+            .shared_symbols(self.symbols.clone())
+    }
+
+    fn render_shared(&self, area: Rect, buffer: &mut Buffer, labels: bool) {
+        let geometry = self.bar(0., "".into(), Style::default()).geometry(area);
+        let ends = (geometry.end(self.values.0), geometry.end(self.values.1));
+        // Quadrants of the first value by half cell along the bar, those of the second one lie
+        // below (horizontal) or right (vertical) of them
+        let halves = match self.direction {
+            Direction::Horizontal => [1, 2],
+            Direction::Vertical => [4, 1],
+        };
+        let shift = match self.direction {
+            Direction::Horizontal => 2,
+            Direction::Vertical => 1,
+        };
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let index = match self.direction {
+                    Direction::Horizontal => geometry.index(x),
+                    Direction::Vertical => geometry.index(y),
+                };
+                let quadrants = |end| quadrants(&geometry, index, end, halves);
+                let (first, second) = (quadrants(ends.0), quadrants(ends.1) << shift);
+                let cell = buffer.get_mut(x, y);
+                match (first, second) {
+                    (0, 0) => {
+                        cell.set_symbol(" ");
+                    }
+                    (0, _) => {
+                        cell.set_char(quadrant_char(second));
+                        cell.set_style(self.styles.1);
+                    }
+                    _ => {
+                        cell.set_char(quadrant_char(first));
+                        cell.set_style(self.styles.0);
+                        if let (15, Some(color)) = (first | second, self.styles.1.fg) {
+                            cell.set_bg(color);
+                        }
+                    }
+                }
+            }
+        }
+        if labels {
+            self.render_labels(&geometry, buffer);
+        }
+    }
+
+    /// Both labels next to each other around zero, if they fit
+    fn render_labels(&self, geometry: &Geometry, buffer: &mut Buffer) {
+        let area = geometry.area();
+        let text: Vec<_> = [
+            (&self.labels.0, self.styles.0),
+            (&self.labels.1, self.styles.1),
+        ]
+        .into_iter()
+        .filter(|(label, _)| !label.is_empty())
+        .collect();
+        let width = text.iter().map(|(l, _)| l.chars().count()).sum::<usize>() + text.len();
+        let width = width.saturating_sub(1) as u16;
+        if width == 0 || width > area.width {
+            // Not enough space to render labels
+            return;
+        }
+        let y = match self.direction {
+            Direction::Horizontal => area.top(),
+            Direction::Vertical => geometry.coordinate(geometry.zero_cell()),
+        };
+        let mut x = area.left() + (area.width - width) / 2;
+        for (label, style) in text {
+            buffer.set_string(x, y, label, style);
+            x += label.chars().count() as u16 + 1;
+        }
+    }
+}
+
+/// The quadrants covered by a bar ending at `end` in the cell with `index`, using the bits
+/// `halves` for the two half cells along the bar. Half cells count as covered by at least half.
+fn quadrants(geometry: &Geometry, index: u16, end: i32, halves: [u8; 2]) -> u8 {
+    let zero = geometry.zero();
+    let (lo, hi) = (zero.min(end), zero.max(end));
+    halves
+        .into_iter()
+        .enumerate()
+        .filter(|(half, _)| {
+            let start = 8 * index as i32 + 4 * *half as i32;
+            hi.min(start + 4) - lo.max(start) >= 2
+        })
+        .fold(0, |quadrants, (_, bit)| quadrants | bit)
+}
+
+impl<'a> Widget for SplitBar<'a> {
+    fn render(mut self, area: Rect, buffer: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buffer);
+                inner
+            }
+            None => area,
+        };
+        if area.width < 1 || area.height < 1 {
+            // Not enough space to render?
+            return;
+        }
+
+        let thickness = match self.direction {
+            Direction::Horizontal => area.height,
+            Direction::Vertical => area.width,
+        };
+        let half = thickness / 2;
+        let (first, shared, second) = match self.direction {
+            Direction::Horizontal => (
+                Rect {
+                    height: half,
+                    ..area
+                },
+                Rect {
+                    y: area.top() + half,
+                    height: thickness % 2,
+                    ..area
+                },
+                Rect {
+                    y: area.bottom() - half,
+                    height: half,
+                    ..area
+                },
+            ),
+            Direction::Vertical => (
+                Rect {
+                    width: half,
+                    ..area
+                },
+                Rect {
+                    x: area.left() + half,
+                    width: thickness % 2,
+                    ..area
+                },
+                Rect {
+                    x: area.right() - half,
+                    width: half,
+                    ..area
+                },
+            ),
+        };
+
+        let labels = self.labels.clone();
+        self.bar(self.values.0, labels.0, self.styles.0)
+            .render(first, buffer);
+        self.bar(self.values.1, labels.1, self.styles.1)
+            .render(second, buffer);
+        if !shared.is_empty() {
+            // Without halves of their own, the labels go into the shared row
+            self.render_shared(shared, buffer, half == 0);
+        }
+    }
+}
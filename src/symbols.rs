@@ -0,0 +1,199 @@
+use std::fmt;
+
+use ratatui::layout::Direction;
+
+/// The set of glyphs used to draw the cells of a bar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Symbols {
+    /// Eighth blocks (`▏`…`█`), with the right- and upper-aligned ones (`▕`, `🮇`, …) taken from
+    /// the Unicode 13 "Symbols for Legacy Computing" block. Gives 8 steps per cell.
+    #[default]
+    Eighths,
+    /// Eighth blocks from the standard "Block Elements" only. The positive side keeps its
+    /// 8 steps per cell, the negative side is rounded to `▕`/`▐`/`█` (or `▔`/`▀`/`█`).
+    Blocks,
+    /// Half blocks (`▌`, `▐`, `▄`, `▀`) and full blocks only. Gives 2 steps per cell.
+    HalfBlocks,
+    /// Plain ASCII (`-`, `=`, `#`) for fonts without any block glyphs. Gives 3 steps per cell.
+    Ascii,
+    /// Braille dots (`⡇`, `⣶`, …). Gives 2 steps per cell horizontally and 4 vertically, but
+    /// renders on any terminal with braille support. Sparklines pack two samples into one cell.
+    Braille,
+}
+
+/// Maps how much of a cell is filled to the glyph drawn into it
+///
+/// Implement this to draw bars with custom characters. [Symbols] provides the built-in sets.
+pub trait BarSymbols: fmt::Debug {
+    /// The glyph for a cell which is filled `eighths` (`0..=8`) of its length. Positive bars fill
+    /// the cell from its left (horizontal) or bottom (vertical) edge, `negative` ones from the
+    /// right or top edge. A positive bar passes `0` for the cell right behind its end.
+    fn symbol(&self, eighths: u8, negative: bool, direction: Direction) -> &str;
+}
+
+impl BarSymbols for Symbols {
+    fn symbol(&self, eighths: u8, negative: bool, direction: Direction) -> &str {
+        match self {
+            Symbols::Eighths => self::eighths(eighths, negative, direction),
+            Symbols::Blocks => blocks(eighths, negative, direction),
+            Symbols::HalfBlocks => half_blocks(eighths, negative, direction),
+            Symbols::Ascii => ascii(eighths),
+            Symbols::Braille => braille(eighths, negative, direction),
+        }
+    }
+}
+
+fn eighths(eighths: u8, negative: bool, direction: Direction) -> &'static str {
+    use Direction::*;
+    match (eighths, negative, direction) {
+        (8.., _, _) => "█",
+        (7, true, Horizontal) => "🮋",
+        (6, true, Horizontal) => "🮊",
+        (5, true, Horizontal) => "🮉",
+        (4, true, Horizontal) => "▐",
+        (3, true, Horizontal) => "🮈",
+        (2, true, Horizontal) => "🮇",
+        (1, true, Horizontal) => "▕",
+        (0 | 1, false, Horizontal) => "▏",
+        (2, false, Horizontal) => "▎",
+        (3, false, Horizontal) => "▍",
+        (4, false, Horizontal) => "▌",
+        (5, false, Horizontal) => "▋",
+        (6, false, Horizontal) => "▊",
+        (7, false, Horizontal) => "▉",
+        (7, true, Vertical) => "🮆",
+        (6, true, Vertical) => "🮅",
+        (5, true, Vertical) => "🮄",
+        (4, true, Vertical) => "▀",
+        (3, true, Vertical) => "🮃",
+        (2, true, Vertical) => "🮂",
+        (1, true, Vertical) => "▔",
+        (0 | 1, false, Vertical) => "▁",
+        (2, false, Vertical) => "▂",
+        (3, false, Vertical) => "▃",
+        (4, false, Vertical) => "▄",
+        (5, false, Vertical) => "▅",
+        (6, false, Vertical) => "▆",
+        (7, false, Vertical) => "▇",
+        _ => " ",
+    }
+}
+
+fn blocks(eighths: u8, negative: bool, direction: Direction) -> &'static str {
+    use Direction::*;
+    match (eighths, negative, direction) {
+        (7.., true, _) => "█",
+        (3..=6, true, Horizontal) => "▐",
+        (1..=2, true, Horizontal) => "▕",
+        (3..=6, true, Vertical) => "▀",
+        (1..=2, true, Vertical) => "▔",
+        _ => self::eighths(eighths, negative, direction),
+    }
+}
+
+fn half_blocks(eighths: u8, negative: bool, direction: Direction) -> &'static str {
+    use Direction::*;
+    match (steps(eighths, 4), negative, direction) {
+        (2.., _, _) => "█",
+        (1, true, Horizontal) => "▐",
+        (1, false, Horizontal) => "▌",
+        (1, true, Vertical) => "▀",
+        (1, false, Vertical) => "▄",
+        _ => " ",
+    }
+}
+
+fn ascii(eighths: u8) -> &'static str {
+    match eighths {
+        6.. => "#",
+        3..=5 => "=",
+        1..=2 => "-",
+        _ => " ",
+    }
+}
+
+fn braille(eighths: u8, negative: bool, direction: Direction) -> &'static str {
+    use Direction::*;
+    match (braille_steps(eighths, direction), negative, direction) {
+        (2.., _, Horizontal) | (4.., _, Vertical) => "⣿",
+        (1, true, Horizontal) => "⢸",
+        (1, false, Horizontal) => "⡇",
+        (3, true, Vertical) => "⠿",
+        (2, true, Vertical) => "⠛",
+        (1, true, Vertical) => "⠉",
+        (1, false, Vertical) => "⣀",
+        (2, false, Vertical) => "⣤",
+        (3, false, Vertical) => "⣶",
+        _ => " ",
+    }
+}
+
+/// The glyph for a cell of which only the `first` (top or left) or the other half across the bar
+/// is covered. Such cells are drawn with quadrants, giving 2 steps per cell along the bar.
+pub(crate) fn half_cell(
+    eighths: u8,
+    negative: bool,
+    direction: Direction,
+    first: bool,
+) -> &'static str {
+    use Direction::*;
+    match (steps(eighths, 4), negative, direction, first) {
+        (2.., _, Horizontal, true) => "▀",
+        (2.., _, Horizontal, false) => "▄",
+        (2.., _, Vertical, true) => "▌",
+        (2.., _, Vertical, false) => "▐",
+        (1, false, Horizontal, true) | (1, true, Vertical, true) => "▘",
+        (1, true, Horizontal, true) | (1, true, Vertical, false) => "▝",
+        (1, false, Horizontal, false) | (1, false, Vertical, true) => "▖",
+        (1, true, Horizontal, false) | (1, false, Vertical, false) => "▗",
+        _ => " ",
+    }
+}
+
+/// Round a fill of `eighths` to a coarser number of steps
+fn steps(eighths: u8, eighths_per_step: u8) -> u8 {
+    (eighths + eighths_per_step / 2) / eighths_per_step
+}
+
+/// Round a fill of `eighths` to the number of dot columns (horizontal) or rows (vertical)
+fn braille_steps(eighths: u8, direction: Direction) -> u8 {
+    match direction {
+        Direction::Horizontal => steps(eighths, 4),
+        Direction::Vertical => steps(eighths, 2),
+    }
+}
+
+/// The bit of each braille dot by row (top to bottom) and column (left, right)
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// The bit of the braille dot in `row` (`0..4`, from the top) and `column` (`0..2`)
+pub(crate) fn braille_dot(row: u8, column: u8) -> u8 {
+    BRAILLE_DOTS[row as usize][column as usize]
+}
+
+/// The quadrant glyph for a pattern of quadrants: `1` top left, `2` top right, `4` bottom left
+/// and `8` bottom right. A space if none is set.
+pub(crate) fn quadrant_char(quadrants: u8) -> char {
+    const QUADRANTS: [char; 16] = [
+        ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+    ];
+    QUADRANTS[quadrants as usize & 0xf]
+}
+
+/// The dot pattern of a single braille column (`0` left, `1` right) filled `eighths`
+/// vertically. A `negative` column is filled from the top.
+pub(crate) fn braille_column(eighths: u8, negative: bool, column: u8) -> u8 {
+    let steps = braille_steps(eighths, Direction::Vertical).min(4) as usize;
+    let rows = if negative { 0..steps } else { (4 - steps)..4 };
+    BRAILLE_DOTS[rows]
+        .iter()
+        .fold(0, |dots, row| dots | row[column as usize])
+}
+
+/// The braille glyph for a pattern of dots, or a space if no dot is set
+pub(crate) fn braille_char(dots: u8) -> char {
+    match dots {
+        0 => ' ',
+        _ => char::from_u32(0x2800 + dots as u32).unwrap_or(' '),
+    }
+}
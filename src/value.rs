@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+/// A number shown by a bar, convertible from all primitive numbers and [Duration]s
+///
+/// Values are kept as `f64`, so `f64` telemetry and large integer counters don't lose precision
+/// before they are mapped to cells. A [Duration] counts in seconds, so value and range of a bar
+/// can both be given as durations. A missing value ([None]) becomes `NaN`, which bars show as
+/// "no data".
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BarValue(f64);
+
+impl BarValue {
+    /// The value as `f64`
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl From<BarValue> for f64 {
+    fn from(value: BarValue) -> Self {
+        value.0
+    }
+}
+
+impl From<Duration> for BarValue {
+    fn from(duration: Duration) -> Self {
+        Self(duration.as_secs_f64())
+    }
+}
+
+impl<T> From<Option<T>> for BarValue
+where
+    T: Into<BarValue>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self(f64::NAN), Into::into)
+    }
+}
+
+macro_rules! from_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BarValue {
+                fn from(value: $t) -> Self {
+                    Self(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+from_primitive!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
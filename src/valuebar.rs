@@ -1,21 +1,113 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, error::Error, fmt, sync::Arc};
 
 use ratatui::{
     buffer::Buffer,
     layout::{Direction, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::{Block, Widget},
 };
 
+use crate::{
+    scale::DynScale, symbols::half_cell, BarSymbols, BarValue, Geometry, Linear, Scale, SymLog,
+    Symbols,
+};
+
 /// A symmetrical gauge for a value
+///
+/// Zero sits exactly in the middle of the bar, so `-range` and `range` fill the same number of
+/// eighths. If the bar is an odd number of cells long, zero lies in the middle of the center cell,
+/// which is then filled in halves (`▌`/`▐` or `▄`/`▀`).
+///
+/// Infinite values fill the bar up to its end. A `NaN` value, or a range which is not finite and
+/// positive, is shown as "no data": a dashed track (`╌` or `╎`) labeled `--`.
 #[derive(Debug, Clone)]
 pub struct ValueBar<'a> {
-    value: f32,
+    value: f64,
     label: Cow<'a, str>,
     direction: Direction,
     style: Style,
     block: Option<Block<'a>>,
-    range: f32,
+    range: f64,
+    scale: Arc<dyn DynScale>,
+    period: Option<f64>,
+    symbols: Arc<dyn BarSymbols + Send + Sync + 'a>,
+    negative_fill: NegativeFill,
+    thickness: Thickness,
+    align: Align,
+    track_symbol: &'a str,
+    track_style: Style,
+    zero_line: Option<&'a str>,
+    zero_line_style: Style,
+    transparent: bool,
+    dead_band: Option<f64>,
+    dead_band_style: Style,
+    snap_to_zero: bool,
+    mirrored: bool,
+}
+
+/// How the partially filled cell at the end of a negative bar is drawn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NegativeFill {
+    /// Use the right- or upper-aligned glyphs of the bar's [BarSymbols] (default)
+    #[default]
+    Glyphs,
+    /// Use the left- or lower-aligned glyph for the unfilled part with foreground and background
+    /// swapped. Looks the same as [NegativeFill::Glyphs], but only needs glyphs every font has.
+    Inverted,
+}
+
+/// How thick a bar is across the direction it fills in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Thickness {
+    /// Fill the whole area (default)
+    #[default]
+    Full,
+    /// A number of whole cells
+    Cells(u16),
+    /// A number of half cells. Half-covered cells are drawn with `▀`/`▄` (horizontal) or `▌`/`▐`
+    /// (vertical) and quadrant glyphs, so they only have 2 steps per cell along the bar.
+    HalfCells(u16),
+}
+
+/// Where a bar thinner than its area is placed across the direction it fills in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Top (horizontal) or left (vertical) edge of the area
+    Start,
+    /// Middle of the area (default)
+    #[default]
+    Center,
+    /// Bottom (horizontal) or right (vertical) edge of the area
+    End,
+}
+
+/// Why a [ValueBar] can't be configured as requested
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueBarError {
+    /// The range is zero, negative, infinite or `NaN`
+    InvalidRange(f64),
+}
+
+impl fmt::Display for ValueBarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueBarError::InvalidRange(range) => {
+                write!(f, "range must be finite and positive, got {range}")
+            }
+        }
+    }
+}
+
+impl Error for ValueBarError {}
+
+/// Which part of a cell across the bar is covered by its thickness
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cover {
+    Full,
+    /// Top or left half
+    First,
+    /// Bottom or right half
+    Second,
 }
 
 impl<'a> Default for ValueBar<'a> {
@@ -23,27 +115,64 @@ impl<'a> Default for ValueBar<'a> {
         Self {
             value: 0.,
             range: 1.,
+            scale: Arc::new(Linear),
+            period: None,
             direction: Direction::Horizontal,
             label: "".into(),
             style: Style::default(),
             block: None,
+            symbols: Arc::new(Symbols::default()),
+            negative_fill: NegativeFill::default(),
+            thickness: Thickness::default(),
+            align: Align::default(),
+            track_symbol: " ",
+            track_style: Style::default(),
+            zero_line: None,
+            zero_line_style: Style::default(),
+            transparent: false,
+            dead_band: None,
+            dead_band_style: Style::default(),
+            snap_to_zero: false,
+            mirrored: false,
         }
     }
 }
 
 impl<'a> ValueBar<'a> {
-    /// Set the value how much this bar should be filled. Should be between [`-range`..`range`]
-    pub fn value(mut self, value: f32) -> Self {
-        self.value = value;
+    /// Set the value how much this bar should be filled. Should be between [`-range`..`range`].
+    /// Accepts any primitive number or a [Duration](std::time::Duration), see [BarValue].
+    pub fn value<T>(mut self, value: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.value = value.into().get();
         self
     }
 
-    /// The upper and lower bound of the gauge.
-    pub fn range(mut self, range: f32) -> Self {
-        self.range = range;
+    /// The upper and lower bound of the gauge. Accepts the same types as
+    /// [value](ValueBar::value). A range which is not finite and positive renders as "no data",
+    /// use [try_range](ValueBar::try_range) to catch it early.
+    pub fn range<T>(mut self, range: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.range = range.into().get();
         self
     }
 
+    /// Like [range](ValueBar::range), but fails if `range` is not finite and positive
+    pub fn try_range<T>(self, range: T) -> Result<Self, ValueBarError>
+    where
+        T: Into<BarValue>,
+    {
+        let range = range.into().get();
+        if range.is_finite() && range > 0. {
+            Ok(self.range(range))
+        } else {
+            Err(ValueBarError::InvalidRange(range))
+        }
+    }
+
     /// Show a label at the zero position of the bar. By default no label is shown.
     /// If width of bar is too small, the label won't be rendered.
     pub fn label<T>(mut self, label: T) -> Self
@@ -54,6 +183,39 @@ impl<'a> ValueBar<'a> {
         self
     }
 
+    /// Select how values are mapped to positions along the bar, either one of the built-in
+    /// scales or a custom [Scale] implementation. Defaults to [Linear]. The scale is part of
+    /// the [Geometry], which compares scales by their value.
+    pub fn scale<S>(mut self, scale: S) -> Self
+    where
+        S: Scale + PartialEq + Send + Sync + 'static,
+    {
+        self.scale = Arc::new(scale);
+        self
+    }
+
+    /// Map values to positions with a symmetric log scale, `sign(v) * ln(1 + |v| / linear_region)`,
+    /// for values spanning several orders of magnitude. Values well within `linear_region` around
+    /// zero are mapped about linearly. The scale stays linear if `linear_region` is not positive.
+    /// Shorthand for [scale](ValueBar::scale) with [SymLog].
+    pub fn log_scale<T>(self, linear_region: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.scale(SymLog::new(linear_region.into().get()))
+    }
+
+    /// Treat values as cyclic, e.g. headings with a `period` of `360.` or phases with `2π`. Values
+    /// are wrapped into `-period/2..period/2` around zero, so 350° is shown as -10°. Set the range
+    /// to `period / 2` to use the whole bar.
+    pub fn cyclic<T>(mut self, period: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.period = Some(period.into().get());
+        self
+    }
+
     /// Set that this bar is filling horizontally (default) or vertically
     pub fn direction(mut self, direction: Direction) -> Self {
         self.direction = direction;
@@ -72,95 +234,288 @@ impl<'a> ValueBar<'a> {
         self
     }
 
-    fn symbol(&self, p: i32) -> &str {
-        use Direction::*;
-        let negative = self.value < 0.;
-        match (p, negative, &self.direction) {
-            (..=-8, true, Horizontal) => "█",
-            (-7, true, Horizontal) => "🮋",
-            (-6, true, Horizontal) => "🮊",
-            (-5, true, Horizontal) => "🮉",
-            (-4, true, Horizontal) => "▐",
-            (-3, true, Horizontal) => "🮈",
-            (-2, true, Horizontal) => "🮇",
-            (-1, true, Horizontal) => "▕",
-            (0 | 1, false, Horizontal) => "▏",
-            (2, false, Horizontal) => "▎",
-            (3, false, Horizontal) => "▍",
-            (4, false, Horizontal) => "▌",
-            (5, false, Horizontal) => "▋",
-            (6, false, Horizontal) => "▊",
-            (7, false, Horizontal) => "▉",
-            (8.., false, Horizontal) => "█",
-            (..=-8, true, Vertical) => "█",
-            (-7, true, Vertical) => "🮆",
-            (-6, true, Vertical) => "🮅",
-            (-5, true, Vertical) => "🮄",
-            (-4, true, Vertical) => "▀",
-            (-3, true, Vertical) => "🮃",
-            (-2, true, Vertical) => "🮂",
-            (-1, true, Vertical) => "▔",
-            (0 | 1, false, Vertical) => "▁",
-            (2, false, Vertical) => "▂",
-            (3, false, Vertical) => "▃",
-            (4, false, Vertical) => "▄",
-            (5, false, Vertical) => "▅",
-            (6, false, Vertical) => "▆",
-            (7, false, Vertical) => "▇",
-            (8.., false, Vertical) => "█",
-            _ => " ",
-        }
+    pub(crate) fn patch_style(mut self, style: Style) -> Self {
+        self.style = self.style.patch(style);
+        self
+    }
+
+    /// Select the glyphs used to draw the bar, either one of the built-in [Symbols] or a custom
+    /// [BarSymbols] implementation. Defaults to [Symbols::Eighths].
+    pub fn symbols<S>(mut self, symbols: S) -> Self
+    where
+        S: BarSymbols + Send + Sync + 'a,
+    {
+        self.symbols = Arc::new(symbols);
+        self
+    }
+
+    pub(crate) fn shared_symbols(
+        mut self,
+        symbols: Arc<dyn BarSymbols + Send + Sync + 'a>,
+    ) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Select how the partially filled cell of a negative bar is drawn.
+    /// Defaults to [NegativeFill::Glyphs].
+    pub fn negative_fill(mut self, negative_fill: NegativeFill) -> Self {
+        self.negative_fill = negative_fill;
+        self
+    }
+
+    /// Set how thick the bar is across its direction. Cells not covered are left untouched.
+    /// Defaults to [Thickness::Full].
+    pub fn thickness(mut self, thickness: Thickness) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Set where a bar thinner than its area is placed. Defaults to [Align::Center].
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the symbol drawn into the unfilled cells of the bar, e.g. `░`. Defaults to `" "`.
+    pub fn track_symbol(mut self, symbol: &'a str) -> Self {
+        self.track_symbol = symbol;
+        self
+    }
+
+    /// Set the style of the unfilled cells, patched onto the bar's style. Its background is also
+    /// used behind the partially filled cell at the end of the bar.
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+
+    /// Mark the zero position with a symbol, e.g. `│` for horizontal or `─` for vertical bars.
+    /// The marker is only drawn where the bar doesn't fill the zero cell. By default no marker
+    /// is shown.
+    pub fn zero_line(mut self, symbol: &'a str) -> Self {
+        self.zero_line = Some(symbol);
+        self
+    }
+
+    /// Set the style patched onto the cells at the zero position, e.g. a background color.
+    /// Applies with or without a [zero_line](ValueBar::zero_line) symbol.
+    pub fn zero_line_style(mut self, style: Style) -> Self {
+        self.zero_line_style = style;
+        self
+    }
+
+    /// Leave unfilled cells untouched instead of clearing them, so content and backgrounds
+    /// set by parent widgets show through. The track is not drawn in this mode.
+    pub fn transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    /// Mark values within `-dead_band..=dead_band` as within tolerance, e.g. small deviations a
+    /// controller ignores. The cells overlapping this region are drawn with the
+    /// [dead_band_style](ValueBar::dead_band_style). By default there is no dead-band.
+    pub fn dead_band<T>(mut self, dead_band: T) -> Self
+    where
+        T: Into<BarValue>,
+    {
+        self.dead_band = Some(dead_band.into().get());
+        self
+    }
+
+    /// Set the style patched onto the cells of the [dead_band](ValueBar::dead_band), e.g. a
+    /// background color
+    pub fn dead_band_style(mut self, style: Style) -> Self {
+        self.dead_band_style = style;
+        self
+    }
+
+    /// Render values within the [dead_band](ValueBar::dead_band) as zero
+    pub fn snap_to_zero(mut self, snap: bool) -> Self {
+        self.snap_to_zero = snap;
+        self
+    }
+
+    /// Fill the bar symmetrically outward from zero by the magnitude of the value, e.g. for a
+    /// stereo width or a vibration amplitude. Both sides use the same glyphs as a positive and a
+    /// negative bar of that magnitude.
+    pub fn mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Where values land when this bar is rendered into `area`
+    pub fn geometry(&self, area: Rect) -> Geometry {
+        let area = match &self.block {
+            Some(block) => block.inner(area),
+            None => area,
+        };
+        Geometry::new(area, self.direction, self.range)
+            .scale(self.scale.clone())
+            .cyclic(self.period)
+    }
+
+    /// Whether there is a value to show, or the bar should render as "no data"
+    fn has_data(&self) -> bool {
+        !self.value.is_nan() && self.range.is_finite() && self.range > 0.
+    }
+
+    /// The covered range across the bar in half cells, counted from the start of the area
+    fn band(&self, width: u16) -> (u16, u16) {
+        let (halves, offset) = match self.thickness {
+            Thickness::Full => return (0, 2 * width),
+            Thickness::Cells(n) => {
+                let n = n.min(width);
+                let offset = match self.align {
+                    Align::Start => 0,
+                    Align::Center => (width - n) / 2,
+                    Align::End => width - n,
+                };
+                (2 * n, 2 * offset)
+            }
+            Thickness::HalfCells(n) => {
+                let n = n.min(2 * width);
+                let offset = match self.align {
+                    Align::Start => 0,
+                    Align::Center => (2 * width - n) / 2,
+                    Align::End => 2 * width - n,
+                };
+                (n, offset)
+            }
+        };
+        (offset, offset + halves)
     }
 }
 
 impl<'a> Widget for ValueBar<'a> {
     fn render(mut self, area: Rect, buffer: &mut Buffer) {
-        let area = match self.block.take() {
-            Some(block) => {
-                let inner = block.inner(area);
-                block.render(area, buffer);
-                inner
-            }
-            None => area,
-        };
-        let (length, width, start) = match self.direction {
-            Direction::Horizontal => (area.width, area.height, area.left()),
-            Direction::Vertical => (area.height, area.width, area.top()),
+        let geometry = self.geometry(area);
+        if let Some(block) = self.block.take() {
+            block.render(area, buffer);
+        }
+        let area = geometry.area();
+        let (length, width) = match self.direction {
+            Direction::Horizontal => (area.width, area.height),
+            Direction::Vertical => (area.height, area.width),
         };
-        if width < 1 {
+        if width < 1 || length < 1 {
             // Not enough space to render?
             return;
         }
 
-        let units_per_px = 2. * self.range / length as f32;
-        let center_row = area.top() + area.height.saturating_sub(1) / 2;
-        let center_col = start + length / 2;
-        let label_start =
-            (area.left() + area.width / 2).saturating_sub(self.label.len() as u16 / 2);
-        for y in area.top()..area.bottom() {
-            for x in area.left()..area.right() {
-                let px = units_per_px
-                    * match self.direction {
-                        Direction::Horizontal => x as f32 - center_col as f32,
-                        Direction::Vertical => center_row as f32 - y as f32,
-                    };
-                // println!("{center_row} - {y} * {units_per_px}");
-                let symbol = if px < 0. && self.value < 0. {
-                    self.symbol(((self.value - px) / units_per_px * 8. - 8.).round() as i32)
-                } else if px >= 0. && self.value >= 0. {
-                    self.symbol(((self.value - px) / units_per_px * 8.).round() as i32)
-                } else {
-                    " "
+        let (lo, hi) = self.band(width);
+        if lo == hi {
+            return;
+        }
+        let cover = |c: u16| match (lo.max(2 * c), hi.min(2 * c + 2)) {
+            (a, b) if b <= a => None,
+            (a, b) if b - a == 2 => Some(Cover::Full),
+            (a, _) if a == 2 * c => Some(Cover::First),
+            _ => Some(Cover::Second),
+        };
+        let bar = match self.direction {
+            Direction::Horizontal => Rect {
+                y: area.top() + lo / 2,
+                height: hi.div_ceil(2) - lo / 2,
+                ..area
+            },
+            Direction::Vertical => Rect {
+                x: area.left() + lo / 2,
+                width: hi.div_ceil(2) - lo / 2,
+                ..area
+            },
+        };
+
+        let has_data = self.has_data();
+        let track_symbol = match (has_data, self.direction) {
+            (true, _) => self.track_symbol,
+            (false, Direction::Horizontal) => "╌",
+            (false, Direction::Vertical) => "╎",
+        };
+        if !has_data {
+            self.label = "--".into();
+        }
+        let dead_band = self.dead_band.filter(|d| *d > 0.);
+        let value = match dead_band {
+            Some(d) if self.snap_to_zero && geometry.wrapped(self.value).abs() <= d => 0.,
+            _ => self.value,
+        };
+        let end = geometry.end(value);
+        // Mirror after wrapping and scaling, a cyclic value may only turn negative by wrapping
+        let end = match self.mirrored {
+            true => geometry.zero() + (end - geometry.zero()).abs(),
+            false => end,
+        };
+        let dead_band = dead_band.map(|d| (geometry.end(-d), geometry.end(d)));
+        let label_row = match self.direction {
+            Direction::Horizontal => bar.top() + bar.height.saturating_sub(1) / 2,
+            Direction::Vertical => geometry.coordinate(geometry.zero_cell()),
+        };
+        let label_start = (bar.left() + bar.width / 2).saturating_sub(self.label.len() as u16 / 2);
+        let track = self.style.patch(self.track_style);
+        for y in bar.top()..bar.bottom() {
+            for x in bar.left()..bar.right() {
+                let (index, cover) = match self.direction {
+                    Direction::Horizontal => (geometry.index(x), cover(y - area.top())),
+                    Direction::Vertical => (geometry.index(y), cover(x - area.left())),
+                };
+                let fill = match self.mirrored {
+                    true => geometry.mirrored_fill(index, end),
+                    false => geometry.fill(index, end),
+                }
+                .filter(|_| has_data);
+                let eighths = fill.map(|(eighths, _)| eighths);
+                let inverted = self.negative_fill == NegativeFill::Inverted
+                    && cover == Some(Cover::Full)
+                    && matches!(fill, Some((1..=7, true)));
+                let symbol = match (fill, cover) {
+                    (Some((e, far)), Some(Cover::First | Cover::Second)) => {
+                        half_cell(e, far, self.direction, cover == Some(Cover::First))
+                    }
+                    (Some((e, _)), _) if inverted => {
+                        self.symbols.symbol(8 - e, false, self.direction)
+                    }
+                    (Some((e, far)), _) => self.symbols.symbol(e, far, self.direction),
+                    (None, _) => " ",
                 };
 
+                // Half covered cells don't show the track, it would spill into the uncovered half
+                let empty = symbol == " " && cover == Some(Cover::Full);
                 let cell = buffer.get_mut(x, y);
-                cell.set_style(self.style);
-                cell.set_symbol(symbol);
+                if empty && self.transparent && has_data {
+                    // Leave the cell as the parent left it
+                } else if empty {
+                    cell.set_style(track);
+                    cell.set_symbol(track_symbol);
+                } else {
+                    cell.set_style(self.style);
+                    cell.set_symbol(symbol);
+                }
+                if let (Some(0..=7), Some(Cover::Full), Some(bg)) =
+                    (eighths, cover, self.track_style.bg)
+                {
+                    cell.set_bg(bg);
+                }
+                if inverted {
+                    cell.set_style(Style::default().add_modifier(Modifier::REVERSED));
+                }
+                if let Some((lo, hi)) = dead_band {
+                    let start = 8 * index as i32;
+                    if start < hi && start + 8 > lo {
+                        cell.set_style(self.dead_band_style);
+                    }
+                }
+                if index == geometry.zero_cell() {
+                    cell.set_style(self.zero_line_style);
+                    if let (Some(marker), None | Some(0)) = (self.zero_line, eighths) {
+                        cell.set_symbol(marker);
+                    }
+                }
 
-                if y != center_row {
+                if y != label_row {
                     continue;
                 }
-                if area.width < self.label.len() as u16 {
+                if bar.width < self.label.len() as u16 {
                     // Not enough space to render label
                     continue;
                 }
@@ -173,6 +528,10 @@ impl<'a> Widget for ValueBar<'a> {
                         Style::default()
                             .fg(Color::Reset)
                             .bg(self.style.fg.unwrap_or(Color::Reset))
+                    } else if inverted {
+                        self.style.remove_modifier(Modifier::REVERSED)
+                    } else if empty && (!self.transparent || !has_data) {
+                        track
                     } else {
                         self.style
                     });
@@ -0,0 +1,94 @@
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Direction,
+    widgets::{Block, Borders},
+    Terminal,
+};
+use tui_bars::BipolarBarChart;
+
+const DATA: [(&str, f32); 3] = [("a", 4.), ("b", -2.), ("c", 1.5)];
+
+fn assert_renders(widget: BipolarBarChart, width: u16, height: u16, expected: Buffer) {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            f.render_widget(widget, f.size());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn vertical_chart_renders_bars_around_shared_zero() {
+    assert_renders(
+        BipolarBarChart::default().data(&DATA).range(4.),
+        5,
+        9,
+        Buffer::with_lines(vec![
+            "█    ",
+            "█    ",
+            "█   ▄",
+            "█   █",
+            "  █  ",
+            "  █  ",
+            "     ",
+            "     ",
+            "a b c",
+        ]),
+    )
+}
+
+#[test]
+fn vertical_chart_uses_largest_value_as_default_range() {
+    assert_renders(
+        BipolarBarChart::default()
+            .data(&[("x", -2.), ("y", 1.)])
+            .bar_width(2)
+            .bar_gap(0),
+        4,
+        5,
+        Buffer::with_lines(vec!["  ▁▁", "  ██", "██  ", "██  ", "x y "]),
+    )
+}
+
+#[test]
+fn vertical_chart_truncates_labels_to_bar_width() {
+    assert_renders(
+        BipolarBarChart::default()
+            .data(&[("foo", 1.), ("bar", -1.)])
+            .bar_width(2)
+            .block(Block::default().borders(Borders::ALL)),
+        7,
+        7,
+        Buffer::with_lines(vec![
+            "┌─────┐",
+            "│██   │",
+            "│██   │",
+            "│   ██│",
+            "│   ██│",
+            "│fo ba│",
+            "└─────┘",
+        ]),
+    )
+}
+
+#[test]
+fn horizontal_chart_renders_labels_in_front_of_bars() {
+    assert_renders(
+        BipolarBarChart::default()
+            .data(&DATA)
+            .range(4.)
+            .direction(Direction::Horizontal),
+        10,
+        5,
+        Buffer::with_lines(vec![
+            "a     ████",
+            "          ",
+            "b   ██    ",
+            "          ",
+            "c     █▌  ",
+        ]),
+    )
+}
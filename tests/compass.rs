@@ -0,0 +1,60 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+use test_case::test_case;
+use tui_bars::CompassTape;
+
+fn render(tape: CompassTape, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, 19, height);
+    let mut buffer = Buffer::empty(area);
+    tape.render(area, &mut buffer);
+    buffer
+}
+
+/// One column per 5°
+fn tape<'a>() -> CompassTape<'a> {
+    CompassTape::default().span(95.)
+}
+
+#[test_case(0.,   "  330    0    30   ", "╷╷╷│╷╷╷╷╷│╷╷╷╷╷│╷╷╷" ; "north")]
+#[test_case(355., "   330    0    30  ", "╷╷╷╷│╷╷╷╷╷│╷╷╷╷╷│╷╷" ; "wraps_around_north")]
+#[test_case(-5.,  "   330    0    30  ", "╷╷╷╷│╷╷╷╷╷│╷╷╷╷╷│╷╷" ; "negative_heading")]
+#[test_case(720., "  330    0    30   ", "╷╷╷│╷╷╷╷╷│╷╷╷╷╷│╷╷╷" ; "beyond_period")]
+fn compass_tape_scrolls_with_heading(heading: f64, labels: &str, ticks: &str) {
+    let mut expected = Buffer::with_lines(vec![labels, ticks, "         ▲         "]);
+    expected.get_mut(9, 1).set_style(Style::default());
+    assert_eq!(render(tape().heading(heading), 3), expected);
+}
+
+#[test]
+fn compass_tape_renders_cardinals() {
+    assert_eq!(
+        render(tape().heading(90.).ticks(15, 45).cardinals(true), 2),
+        Buffer::with_lines(vec!["         E         ", "│  ╷  ╷  │  ╷  ╷  │"])
+    );
+}
+
+#[test]
+fn compass_tape_renders_phases() {
+    let tape = CompassTape::default()
+        .period(std::f64::consts::TAU)
+        .span(1.9)
+        .ticks(0.1, 0.5)
+        .precision(1)
+        .heading(0.1);
+    assert_eq!(
+        render(tape, 2),
+        Buffer::with_lines(vec!["  5.8  0.0  0.5    ", "╷╷╷│╷╷╷╷│╷╷╷╷│╷╷╷╷│"])
+    );
+}
+
+#[test]
+fn compass_tape_highlights_marker_and_drops_rows() {
+    let tape = tape().marker_style(Style::default().fg(Color::Yellow));
+    let mut expected = Buffer::with_lines(vec!["╷╷╷│╷╷╷╷╷│╷╷╷╷╷│╷╷╷"]);
+    expected.get_mut(9, 0).set_fg(Color::Yellow);
+    assert_eq!(render(tape, 1), expected);
+}
@@ -0,0 +1,145 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    widgets::{Block, Borders, Widget},
+};
+use test_case::test_case;
+use tui_bars::ValueBar;
+
+const RANGE: f32 = 5.;
+
+fn horizontal() -> ValueBar<'static> {
+    ValueBar::default().range(RANGE)
+}
+
+fn vertical() -> ValueBar<'static> {
+    ValueBar::default()
+        .range(RANGE)
+        .direction(Direction::Vertical)
+}
+
+#[test_case(0.,          (5, 0) ; "zero")]
+#[test_case(1. / 8.,     (5, 1) ; "positive_one_eighths")]
+#[test_case(RANGE*0.3,   (6, 4) ; "positive_range_30_percent")]
+#[test_case(RANGE*1.0,   (9, 8) ; "positive_range_full")]
+#[test_case(RANGE*2.0,   (9, 8) ; "positive_clamped")]
+#[test_case(-1. / 8.,    (4, 7) ; "negative_one_eighths")]
+#[test_case(-RANGE*0.3,  (3, 4) ; "negative_range_30_percent")]
+#[test_case(-RANGE*1.0,  (0, 0) ; "negative_range_full")]
+fn horizontal_maps_value_to_position(value: f32, position: (u16, u8)) {
+    let geometry = horizontal().geometry(Rect::new(0, 0, 10, 5));
+    assert_eq!(geometry.value_to_position(value), position);
+}
+
+#[test_case(0.,          (4, 0) ; "zero")]
+#[test_case(RANGE*0.3,   (3, 4) ; "positive_range_30_percent")]
+#[test_case(-RANGE*0.3,  (6, 4) ; "negative_range_30_percent")]
+#[test_case(-RANGE*1.0,  (9, 0) ; "negative_range_full")]
+fn vertical_maps_value_to_position(value: f32, position: (u16, u8)) {
+    let geometry = vertical().geometry(Rect::new(0, 0, 5, 10));
+    assert_eq!(geometry.value_to_position(value), position);
+}
+
+#[test_case(0,  -5.                        ; "first_cell")]
+#[test_case(4,  (2. * (4. / 9.) - 1.) * 5. ; "last_negative_cell")]
+#[test_case(5,  (2. * (5. / 9.) - 1.) * 5. ; "first_positive_cell")]
+#[test_case(9,  5.                         ; "last_cell")]
+#[test_case(42, 5.                         ; "outside_area")]
+fn horizontal_maps_position_to_value(x: u16, value: f64) {
+    let geometry = horizontal().geometry(Rect::new(0, 0, 10, 5));
+    assert_eq!(geometry.position_to_value(x, 2), value);
+}
+
+#[test]
+fn vertical_maps_position_to_value_inside_block() {
+    let geometry = vertical()
+        .block(Block::default().borders(Borders::ALL))
+        .geometry(Rect::new(0, 0, 5, 10));
+    assert_eq!(geometry.area(), Rect::new(1, 1, 3, 8));
+    assert_eq!(geometry.position_to_value(2, 1), 5.);
+    assert_eq!(geometry.position_to_value(2, 4), (2. * (4. / 7.) - 1.) * 5.);
+    assert_eq!(geometry.position_to_value(2, 5), (2. * (3. / 7.) - 1.) * 5.);
+    assert_eq!(geometry.position_to_value(2, 8), -5.);
+    assert_eq!(geometry.position_to_value(2, 0), 5.);
+}
+
+#[test_case(RANGE*0.3, "▌" ; "positive")]
+#[test_case(-RANGE*0.3, "▐" ; "negative")]
+fn geometry_agrees_with_render(value: f32, symbol: &str) {
+    let area = Rect::new(0, 0, 10, 1);
+    let bar = horizontal().value(value);
+    let (x, eighths) = bar.geometry(area).value_to_position(value);
+    let mut buffer = Buffer::empty(area);
+    bar.render(area, &mut buffer);
+    assert_eq!(eighths, 4);
+    assert_eq!(buffer.get(x, 0).symbol, symbol);
+}
+
+#[test_case(1.,     (5, 4) ; "one")]
+#[test_case(10.,    (6, 6) ; "ten")]
+#[test_case(100.,   (8, 3) ; "hundred")]
+#[test_case(1000.,  (9, 8) ; "range")]
+#[test_case(-10.,   (3, 2) ; "negative_ten")]
+#[test_case(0.,     (5, 0) ; "zero")]
+fn log_scale_maps_value_to_position(value: f64, position: (u16, u8)) {
+    let geometry = ValueBar::default()
+        .range(1000.)
+        .log_scale(1.)
+        .geometry(Rect::new(0, 0, 10, 1));
+    assert_eq!(geometry.value_to_position(value), position);
+}
+
+#[test]
+fn log_scale_maps_position_back_to_same_cell() {
+    let geometry = ValueBar::default()
+        .range(1000.)
+        .log_scale(1.)
+        .geometry(Rect::new(0, 0, 10, 1));
+    for x in 0..10 {
+        let (cell, _) = geometry.value_to_position(geometry.position_to_value(x, 0));
+        assert_eq!(cell, x);
+    }
+    assert!(geometry.position_to_value(5, 0) < 2.);
+    assert!(geometry.position_to_value(9, 0) > 100.);
+}
+
+#[test]
+fn log_scale_with_invalid_linear_region_stays_linear() {
+    let geometry = horizontal().log_scale(0.).geometry(Rect::new(0, 0, 10, 5));
+    assert_eq!(geometry.value_to_position(RANGE * 0.3), (6, 4));
+}
+
+#[test_case(horizontal(), Rect::new(3, 2, 0, 5) ; "horizontal")]
+#[test_case(vertical(),   Rect::new(3, 2, 5, 0) ; "vertical")]
+fn empty_area_maps_without_panicking(bar: ValueBar, area: Rect) {
+    let geometry = bar.geometry(area);
+    assert_eq!(geometry.value_to_position(RANGE).1, 0);
+    assert_eq!(geometry.position_to_value(4, 3), 0.);
+}
+
+#[test_case(-1.      ; "negative")]
+#[test_case(f64::NAN ; "nan")]
+fn invalid_range_maps_position_to_nan(range: f64) {
+    let geometry = ValueBar::default()
+        .range(range)
+        .geometry(Rect::new(0, 0, 10, 5));
+    assert!(geometry.position_to_value(3, 0).is_nan());
+}
+
+#[test]
+fn geometries_compare_their_scales() {
+    let area = Rect::new(0, 0, 10, 5);
+    assert_eq!(horizontal().geometry(area), horizontal().geometry(area));
+    assert_eq!(
+        horizontal().log_scale(1.).geometry(area),
+        horizontal().log_scale(1.).geometry(area)
+    );
+    assert_ne!(
+        horizontal().log_scale(1.).geometry(area),
+        horizontal().log_scale(2.).geometry(area)
+    );
+    assert_ne!(
+        horizontal().geometry(area),
+        horizontal().log_scale(1.).geometry(area)
+    );
+}
@@ -1,13 +1,16 @@
+use std::time::Duration;
+
 use itertools::Itertools;
 use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
-    style::{Color, Style},
-    widgets::{Block, Borders},
+    layout::{Direction, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Widget},
     Terminal,
 };
 use test_case::test_case;
-use tui_bars::ValueBar;
+use tui_bars::{Align, BarSymbols, NegativeFill, Symbols, Thickness, ValueBar, ValueBarError};
 
 const TERMINAL_WIDTH: u16 = 10;
 const RANGE: f32 = 5.;
@@ -260,3 +263,534 @@ fn horizontal_renders_with_style_and_label(color: Color) {
         expected,
     )
 }
+
+#[test_case(0.,         "          " ; "zero")]
+#[test_case(1. / 8.,    "          " ; "positive_one_eighths")]
+#[test_case(2. / 8.,    "     ⡇    " ; "positive_two_eighths")]
+#[test_case(4. / 8.,    "     ⡇    " ; "positive_four_eighths")]
+#[test_case(6. / 8.,    "     ⣿    " ; "positive_six_eighths")]
+#[test_case(RANGE*0.5,  "     ⣿⣿⡇  " ; "positive_range_50_percent")]
+#[test_case(RANGE*1.0,  "     ⣿⣿⣿⣿⣿" ; "positive_range_full")]
+#[test_case(-2. / 8.,   "    ⢸     " ; "negative_two_eighths")]
+#[test_case(-6. / 8.,   "    ⣿     " ; "negative_six_eighths")]
+#[test_case(-RANGE*0.5, "  ⢸⣿⣿     " ; "negative_range_50_percent")]
+#[test_case(-RANGE*1.0, "⣿⣿⣿⣿⣿     " ; "negative_range_full")]
+fn horizontal_renders_value_with_braille(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .symbols(Symbols::Braille),
+        Buffer::with_lines(vec![line, line, line, line, line]),
+    )
+}
+
+#[test_case(Symbols::Blocks, 3. / 8.,       "     ▍    " ; "blocks_positive_three_eighths")]
+#[test_case(Symbols::Blocks, RANGE*0.3,     "     █▌   " ; "blocks_positive_range_30_percent")]
+#[test_case(Symbols::Blocks, -1. / 8.,      "    ▕     " ; "blocks_negative_one_eighths")]
+#[test_case(Symbols::Blocks, -2. / 8.,      "    ▕     " ; "blocks_negative_two_eighths")]
+#[test_case(Symbols::Blocks, -3. / 8.,      "    ▐     " ; "blocks_negative_three_eighths")]
+#[test_case(Symbols::Blocks, -6. / 8.,      "    ▐     " ; "blocks_negative_six_eighths")]
+#[test_case(Symbols::Blocks, -7. / 8.,      "    █     " ; "blocks_negative_seven_eighths")]
+#[test_case(Symbols::Blocks, -RANGE*0.3,    "   ▐█     " ; "blocks_negative_range_30_percent")]
+#[test_case(Symbols::HalfBlocks, 0.,        "          " ; "half_blocks_zero")]
+#[test_case(Symbols::HalfBlocks, 1. / 8.,   "          " ; "half_blocks_positive_one_eighths")]
+#[test_case(Symbols::HalfBlocks, 2. / 8.,   "     ▌    " ; "half_blocks_positive_two_eighths")]
+#[test_case(Symbols::HalfBlocks, 5. / 8.,   "     ▌    " ; "half_blocks_positive_five_eighths")]
+#[test_case(Symbols::HalfBlocks, 6. / 8.,   "     █    " ; "half_blocks_positive_six_eighths")]
+#[test_case(Symbols::HalfBlocks, RANGE*0.3, "     █▌   " ; "half_blocks_positive_range_30_percent")]
+#[test_case(Symbols::HalfBlocks, -2. / 8.,  "    ▐     " ; "half_blocks_negative_two_eighths")]
+#[test_case(Symbols::HalfBlocks, -6. / 8.,  "    █     " ; "half_blocks_negative_six_eighths")]
+#[test_case(Symbols::HalfBlocks, -RANGE*0.3, "   ▐█     " ; "half_blocks_negative_range_30_percent")]
+#[test_case(Symbols::Ascii, 0.,             "          " ; "ascii_zero")]
+#[test_case(Symbols::Ascii, 1. / 8.,        "     -    " ; "ascii_positive_one_eighths")]
+#[test_case(Symbols::Ascii, 3. / 8.,        "     =    " ; "ascii_positive_three_eighths")]
+#[test_case(Symbols::Ascii, 6. / 8.,        "     #    " ; "ascii_positive_six_eighths")]
+#[test_case(Symbols::Ascii, 8. / 8.,        "     #    " ; "ascii_positive_eight_eighths")]
+#[test_case(Symbols::Ascii, RANGE*0.3,      "     #=   " ; "ascii_positive_range_30_percent")]
+#[test_case(Symbols::Ascii, -1. / 8.,       "    -     " ; "ascii_negative_one_eighths")]
+#[test_case(Symbols::Ascii, -RANGE*0.3,     "   =#     " ; "ascii_negative_range_30_percent")]
+#[test_case(Symbols::Ascii, -RANGE*1.0,     "#####     " ; "ascii_negative_range_full")]
+fn horizontal_renders_value_with_symbols(symbols: Symbols, value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .symbols(symbols),
+        Buffer::with_lines(vec![line, line, line, line, line]),
+    )
+}
+
+#[derive(Debug)]
+struct Lines;
+
+impl BarSymbols for Lines {
+    fn symbol(&self, eighths: u8, negative: bool, _: Direction) -> &str {
+        match (eighths, negative) {
+            (6.., _) => "━",
+            (2..=5, false) => "╸",
+            (2..=5, true) => "╺",
+            _ => " ",
+        }
+    }
+}
+
+#[test_case(0.,         "          " ; "zero")]
+#[test_case(RANGE*0.3,  "     ━╸   " ; "positive_range_30_percent")]
+#[test_case(-RANGE*0.3, "   ╺━     " ; "negative_range_30_percent")]
+#[test_case(-RANGE*1.0, "━━━━━     " ; "negative_range_full")]
+fn horizontal_renders_value_with_custom_symbols(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(RANGE).symbols(Lines),
+        Buffer::with_lines(vec![line, line, line, line, line]),
+    )
+}
+
+#[test_case(-1. / 8.,   "    ▉     ", Some(4) ; "negative_one_eighths")]
+#[test_case(-2. / 8.,   "    ▊     ", Some(4) ; "negative_two_eighths")]
+#[test_case(-4. / 8.,   "    ▌     ", Some(4) ; "negative_four_eighths")]
+#[test_case(-7. / 8.,   "    ▏     ", Some(4) ; "negative_seven_eighths")]
+#[test_case(-8. / 8.,   "    █     ", None    ; "negative_eight_eighths")]
+#[test_case(-RANGE*0.3, "   ▌█     ", Some(3) ; "negative_range_30_percent")]
+#[test_case(RANGE*0.3,  "     █▌   ", None    ; "positive_range_30_percent")]
+fn horizontal_renders_inverted_negative_fill(value: f32, line: &str, inverted: Option<u16>) {
+    let mut expected = Buffer::with_lines(vec![line, line, line, line, line]);
+    if let Some(x) = inverted {
+        for y in 0..5 {
+            expected.get_mut(x, y).modifier = Modifier::REVERSED;
+        }
+    }
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .negative_fill(NegativeFill::Inverted),
+        expected,
+    )
+}
+
+#[test]
+fn horizontal_renders_label_over_inverted_negative_fill() {
+    let mut expected = Buffer::with_lines(vec![
+        "   ▌█     ",
+        "   ▌█     ",
+        "abcdefghij",
+        "   ▌█     ",
+        "   ▌█     ",
+    ]);
+    for y in [0, 1, 3, 4] {
+        expected.get_mut(3, y).modifier = Modifier::REVERSED;
+    }
+    expected.get_mut(4, 2).set_bg(Color::Red);
+    for x in 0..10 {
+        expected
+            .get_mut(x, 2)
+            .set_fg(if x == 4 { Color::Reset } else { Color::Red });
+    }
+    for (x, y) in (0..10).cartesian_product([0, 1, 3, 4]) {
+        expected.get_mut(x, y).set_fg(Color::Red);
+    }
+    assert_renders(
+        ValueBar::default()
+            .value(-RANGE * 0.3)
+            .range(RANGE)
+            .label("abcdefghij")
+            .style(Style::default().fg(Color::Red))
+            .negative_fill(NegativeFill::Inverted),
+        expected,
+    )
+}
+
+#[test_case(Thickness::Cells(1), Align::Center, RANGE*0.3, vec![
+    "          ",
+    "          ",
+    "     █▌   ",
+    "          ",
+    "          ",
+] ; "one_cell_centered")]
+#[test_case(Thickness::Cells(2), Align::Start, RANGE*0.3, vec![
+    "     █▌   ",
+    "     █▌   ",
+    "          ",
+    "          ",
+    "          ",
+] ; "two_cells_at_start")]
+#[test_case(Thickness::Cells(9), Align::End, -RANGE*0.3, vec![
+    "   ▐█     ",
+    "   ▐█     ",
+    "   ▐█     ",
+    "   ▐█     ",
+    "   ▐█     ",
+] ; "too_many_cells")]
+#[test_case(Thickness::HalfCells(1), Align::Start, RANGE*0.3, vec![
+    "     ▀▘   ",
+    "          ",
+    "          ",
+    "          ",
+    "          ",
+] ; "one_half_cell_at_start")]
+#[test_case(Thickness::HalfCells(3), Align::Center, -RANGE*0.3, vec![
+    "          ",
+    "   ▗▄     ",
+    "   ▐█     ",
+    "          ",
+    "          ",
+] ; "three_half_cells_centered")]
+#[test_case(Thickness::HalfCells(2), Align::End, RANGE*0.3, vec![
+    "          ",
+    "          ",
+    "          ",
+    "          ",
+    "     █▌   ",
+] ; "two_half_cells_at_end")]
+fn horizontal_renders_with_thickness(
+    thickness: Thickness,
+    align: Align,
+    value: f32,
+    lines: Vec<&str>,
+) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .thickness(thickness)
+            .align(align),
+        Buffer::with_lines(lines),
+    )
+}
+
+#[test]
+fn horizontal_renders_label_in_middle_of_thickness() {
+    assert_renders(
+        ValueBar::default()
+            .value(RANGE * 0.3)
+            .range(RANGE)
+            .label("abcdefghij")
+            .thickness(Thickness::Cells(3))
+            .align(Align::End),
+        Buffer::with_lines(vec![
+            "          ",
+            "          ",
+            "     █▌   ",
+            "abcdefghij",
+            "     █▌   ",
+        ]),
+    )
+}
+
+#[test]
+fn horizontal_renders_track_symbol_into_unfilled_cells() {
+    assert_renders(
+        ValueBar::default()
+            .value(-RANGE * 0.3)
+            .range(RANGE)
+            .track_symbol("░"),
+        Buffer::with_lines(vec!["░░░▐█░░░░░"; 5]),
+    )
+}
+
+#[test]
+fn horizontal_renders_track_style_behind_unfilled_and_partial_cells() {
+    let mut expected = Buffer::with_lines(vec!["     █▌   "; 5]);
+    let area = expected.area();
+    for (x, y) in (area.left()..area.right()).cartesian_product(area.top()..area.bottom()) {
+        let cell = expected.get_mut(x, y);
+        cell.set_fg(Color::Red);
+        if x != 5 {
+            cell.set_bg(Color::DarkGray);
+        }
+    }
+    assert_renders(
+        ValueBar::default()
+            .value(RANGE * 0.3)
+            .range(RANGE)
+            .style(Style::default().fg(Color::Red))
+            .track_style(Style::default().bg(Color::DarkGray)),
+        expected,
+    )
+}
+
+#[test_case(0.,         "     │    " ; "zero")]
+#[test_case(1. / 8.,    "     ▏    " ; "positive_one_eighths")]
+#[test_case(2. / 8.,    "     ▎    " ; "positive_two_eighths")]
+#[test_case(RANGE*0.3,  "     █▌   " ; "positive_range_30_percent")]
+#[test_case(-RANGE*0.3, "   ▐█│    " ; "negative_range_30_percent")]
+fn horizontal_renders_zero_line(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(RANGE).zero_line("│"),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test]
+fn horizontal_renders_zero_line_style_and_label() {
+    let mut expected = Buffer::with_lines(vec![
+        "   ▐█│    ",
+        "   ▐█│    ",
+        "abcdefghij",
+        "   ▐█│    ",
+        "   ▐█│    ",
+    ]);
+    for y in 0..5 {
+        expected.get_mut(5, y).set_bg(Color::Blue);
+    }
+    assert_renders(
+        ValueBar::default()
+            .value(-RANGE * 0.3)
+            .range(RANGE)
+            .label("abcdefghij")
+            .zero_line("│")
+            .zero_line_style(Style::default().bg(Color::Blue)),
+        expected,
+    )
+}
+
+#[test]
+fn horizontal_renders_transparent_over_existing_content() {
+    let mut buffer = Buffer::with_lines(vec![
+        "..........",
+        "..........",
+        "..........",
+        "..........",
+        "..........",
+    ]);
+    let area = buffer.area;
+    buffer.set_style(area, Style::default().bg(Color::Blue));
+    ValueBar::default()
+        .value(-RANGE * 0.3)
+        .range(RANGE)
+        .label("abcd")
+        .style(Style::default().fg(Color::Red))
+        .transparent(true)
+        .render(area, &mut buffer);
+
+    let mut expected = Buffer::with_lines(vec![
+        "...▐█.....",
+        "...▐█.....",
+        "...abcd...",
+        "...▐█.....",
+        "...▐█.....",
+    ]);
+    expected.set_style(area, Style::default().bg(Color::Blue));
+    for (x, y) in [3, 4]
+        .into_iter()
+        .cartesian_product(area.top()..area.bottom())
+    {
+        expected.get_mut(x, y).set_fg(Color::Red);
+    }
+    for x in 5..7 {
+        expected.get_mut(x, 2).set_fg(Color::Red);
+    }
+    expected
+        .get_mut(4, 2)
+        .set_fg(Color::Reset)
+        .set_bg(Color::Red);
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn horizontal_renders_opaque_over_existing_content_by_default() {
+    let mut buffer = Buffer::with_lines(vec![".........."; 5]);
+    let area = buffer.area;
+    ValueBar::default()
+        .value(RANGE * 0.3)
+        .range(RANGE)
+        .render(area, &mut buffer);
+    assert_eq!(buffer, Buffer::with_lines(vec!["     █▌   "; 5]));
+}
+
+#[test_case(0.,               "│         " ; "zero")]
+#[test_case(RANGE*1./36.,     "│         " ; "positive_one_eighths")]
+#[test_case(RANGE*4./36.,     "│    ▐▏   " ; "positive_four_eighths")]
+#[test_case(RANGE*12./36.,    "│    ▐█▏  " ; "positive_twelve_eighths")]
+#[test_case(RANGE*1.0,        "│    ▐████" ; "positive_range_full")]
+#[test_case(-RANGE*1./36.,    "│         " ; "negative_one_eighths")]
+#[test_case(-RANGE*4./36.,    "│    ▌    " ; "negative_four_eighths")]
+#[test_case(-RANGE*10./36.,   "│   🮊▌    " ; "negative_ten_eighths")]
+#[test_case(-RANGE*12./36.,   "│   █▌    " ; "negative_twelve_eighths")]
+#[test_case(-RANGE*1.0,       "│████▌    " ; "negative_range_full")]
+fn horizontal_renders_value_with_zero_in_middle_of_odd_width(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .block(Block::default().borders(Borders::LEFT)),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(ValueBar::default().value(2.5f64).range(5f64) ; "f64")]
+#[test_case(ValueBar::default().value(25).range(50u8) ; "integers")]
+#[test_case(ValueBar::default().value(250_000_000_000u64).range(500_000_000_000u64) ; "large_integers")]
+#[test_case(ValueBar::default().value(Duration::from_millis(25)).range(Duration::from_millis(50)) ; "durations")]
+fn horizontal_renders_value_of_any_numeric_type(bar: ValueBar) {
+    assert_renders(bar, Buffer::with_lines(vec!["     ██▌  "; 5]))
+}
+
+#[test]
+fn horizontal_renders_negative_integer() {
+    assert_renders(
+        ValueBar::default().value(-3i64).range(5),
+        Buffer::with_lines(vec!["  ███     "; 5]),
+    )
+}
+
+#[test_case(ValueBar::default().value(f32::NAN).range(RANGE) ; "nan")]
+#[test_case(ValueBar::default().value(None::<f32>).range(RANGE) ; "missing")]
+#[test_case(ValueBar::default().value(1.).range(0.) ; "zero_range")]
+#[test_case(ValueBar::default().value(1.).range(-RANGE) ; "negative_range")]
+#[test_case(ValueBar::default().value(1.).range(f64::INFINITY) ; "infinite_range")]
+#[test_case(ValueBar::default().value(1.).range(f64::NAN) ; "nan_range")]
+fn horizontal_renders_no_data(bar: ValueBar) {
+    assert_renders(
+        bar.label("1.0").transparent(true),
+        Buffer::with_lines(vec![
+            "╌╌╌╌╌╌╌╌╌╌",
+            "╌╌╌╌╌╌╌╌╌╌",
+            "╌╌╌╌--╌╌╌╌",
+            "╌╌╌╌╌╌╌╌╌╌",
+            "╌╌╌╌╌╌╌╌╌╌",
+        ]),
+    )
+}
+
+#[test_case(f32::INFINITY,     "     █████" ; "positive")]
+#[test_case(f32::NEG_INFINITY, "█████     " ; "negative")]
+fn horizontal_renders_infinite_value_clamped(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(RANGE),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(0.       ; "zero")]
+#[test_case(-RANGE   ; "negative")]
+#[test_case(f32::NAN ; "nan")]
+#[test_case(f32::INFINITY ; "infinite")]
+fn horizontal_rejects_invalid_range(range: f32) {
+    let error = ValueBar::default().try_range(range).unwrap_err();
+    assert!(matches!(error, ValueBarError::InvalidRange(_)));
+}
+
+#[test]
+fn horizontal_accepts_valid_range() {
+    assert_renders(
+        ValueBar::default().value(2.5).try_range(RANGE).unwrap(),
+        Buffer::with_lines(vec!["     ██▌  "; 5]),
+    )
+}
+
+#[test_case(10.,   "     █▊   " ; "positive")]
+#[test_case(-100., " 🮈███     " ; "negative")]
+fn horizontal_renders_log_scale(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(1000.).log_scale(1.),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(10.,   "     ▎    " ; "small_positive")]
+#[test_case(350.,  "    🮇     " ; "wraps_to_small_negative")]
+#[test_case(-350., "     ▎    " ; "wraps_to_small_positive")]
+#[test_case(190.,  "🮊████     " ; "wraps_to_large_negative")]
+fn horizontal_renders_cyclic_value(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(180).cyclic(360),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(0.5, false, "     ▌    " ; "inside")]
+#[test_case(0.5, true,  "     ▏    " ; "inside_snapped")]
+#[test_case(-1., true,  "     ▏    " ; "edge_snapped")]
+#[test_case(2.,  true,  "     ██▏  " ; "outside")]
+fn horizontal_renders_dead_band(value: f32, snap: bool, line: &str) {
+    let mut expected = Buffer::with_lines(vec![line; 5]);
+    expected.set_style(Rect::new(4, 0, 2, 5), Style::default().bg(Color::DarkGray));
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .dead_band(1.)
+            .dead_band_style(Style::default().bg(Color::DarkGray))
+            .snap_to_zero(snap),
+        expected,
+    )
+}
+
+#[test]
+fn horizontal_dead_band_covers_partial_cells() {
+    let mut expected = Buffer::with_lines(vec!["     ▎    "; 5]);
+    expected.set_style(Rect::new(3, 0, 4, 5), Style::default().bg(Color::DarkGray));
+    assert_renders(
+        ValueBar::default()
+            .value(0.3)
+            .range(RANGE)
+            .dead_band(1.5)
+            .dead_band_style(Style::default().bg(Color::DarkGray)),
+        expected,
+    )
+}
+
+#[test_case(0.,    "     ▏    " ; "zero")]
+#[test_case(2.,    "   ████▏  " ; "positive")]
+#[test_case(-2.,   "   ████▏  " ; "negative")]
+#[test_case(2.5,   "  ▐████▌  " ; "partial")]
+#[test_case(RANGE, "██████████" ; "full")]
+fn horizontal_renders_mirrored_value(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default().value(value).range(RANGE).mirrored(true),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(0.,    "│         " ; "zero")]
+#[test_case(-2.,   "│  🮇███▎  " ; "negative")]
+#[test_case(RANGE, "│█████████" ; "full")]
+fn horizontal_renders_mirrored_value_in_odd_width(value: f32, line: &str) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(RANGE)
+            .mirrored(true)
+            .block(Block::default().borders(Borders::LEFT)),
+        Buffer::with_lines(vec![line; 5]),
+    )
+}
+
+#[test_case(10.  ; "positive")]
+#[test_case(-10. ; "negative")]
+#[test_case(350. ; "wraps_to_negative")]
+fn horizontal_renders_mirrored_cyclic_value(value: f32) {
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(180)
+            .cyclic(360)
+            .mirrored(true),
+        Buffer::with_lines(vec!["    🮇▎    "; 5]),
+    )
+}
+
+#[test]
+fn value_bar_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ValueBar<'static>>();
+}
+
+#[test_case(359. ; "wraps_to_negative")]
+#[test_case(361. ; "wraps_to_positive")]
+fn horizontal_snaps_cyclic_value_to_zero(value: f32) {
+    let mut expected = Buffer::with_lines(vec!["     ▏    "; 5]);
+    expected.set_style(Rect::new(4, 0, 2, 5), Style::default().bg(Color::DarkGray));
+    assert_renders(
+        ValueBar::default()
+            .value(value)
+            .range(180)
+            .cyclic(360)
+            .dead_band(10)
+            .dead_band_style(Style::default().bg(Color::DarkGray))
+            .snap_to_zero(true),
+        expected,
+    )
+}
@@ -0,0 +1,160 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::StatefulWidget,
+};
+use test_case::test_case;
+use tui_bars::{Ballistics, LevelMeter, LevelMeterState};
+
+/// Linear amplitude of a level in dBFS
+fn amplitude(db: f64) -> f64 {
+    10f64.powf(db / 20.)
+}
+
+fn render(meter: LevelMeter, state: &mut LevelMeterState) -> Buffer {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buffer = Buffer::empty(area);
+    meter.render(area, &mut buffer, state);
+    buffer
+}
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn meter_starts_silent() {
+    let mut state = LevelMeterState::default();
+    assert_eq!(state.level_db(), f64::NEG_INFINITY);
+    assert_eq!(
+        render(LevelMeter::default(), &mut state),
+        Buffer::with_lines(vec!["          "])
+    );
+}
+
+#[test_case(-30., "█████     " ; "half")]
+#[test_case(-0.,  "██████████" ; "full_scale")]
+#[test_case(-33., "████▌     " ; "eighths")]
+#[test_case(-90., "          " ; "below_floor")]
+fn meter_renders_level_in_dbfs(db: f64, line: &str) {
+    let mut state = LevelMeterState::new(Ballistics::Direct);
+    state.push_rms(amplitude(db) * 0.999_999, Instant::now());
+    assert_eq!(
+        render(LevelMeter::default(), &mut state),
+        Buffer::with_lines(vec![line])
+    );
+}
+
+#[test]
+fn meter_renders_peak_hold_and_clip() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::new(Ballistics::Direct);
+    state.push_rms(amplitude(-12.), t0);
+    state.push_rms(amplitude(-30.), t0 + ms(100));
+    let meter = || {
+        LevelMeter::default()
+            .peak_style(Style::default().fg(Color::Yellow))
+            .track_symbol("·")
+    };
+    let mut expected = Buffer::with_lines(vec!["█████··▕··"]);
+    expected.get_mut(7, 0).set_fg(Color::Yellow);
+    assert_eq!(render(meter(), &mut state), expected);
+
+    // Clipping samples in a quiet block
+    let mut block = [0.; 64];
+    block[0] = 1.;
+    state.push_samples(&block, t0 + ms(200));
+    assert!(state.is_clipped());
+    state.push_rms(amplitude(-30.), t0 + ms(300));
+    expected.get_mut(9, 0).set_symbol("█").set_fg(Color::Red);
+    assert_eq!(render(meter(), &mut state), expected);
+
+    state.reset_clip();
+    expected.get_mut(9, 0).set_symbol("·").set_fg(Color::Reset);
+    assert_eq!(render(meter(), &mut state), expected);
+}
+
+#[test]
+fn meter_renders_vertically() {
+    let area = Rect::new(0, 0, 1, 4);
+    let mut buffer = Buffer::empty(area);
+    let mut state = LevelMeterState::new(Ballistics::Direct);
+    state.push_rms(amplitude(-22.5), Instant::now());
+    LevelMeter::default()
+        .direction(Direction::Vertical)
+        .render(area, &mut buffer, &mut state);
+    assert_eq!(buffer, Buffer::with_lines(vec![" ", "▄", "█", "█"]));
+}
+
+#[test]
+fn vu_integrates_over_300ms() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::new(Ballistics::Vu);
+    state.push_rms(0., t0);
+    state.push_rms(1., t0 + ms(150));
+    assert!(state.level_db() < -0.5);
+    state.push_rms(1., t0 + ms(300));
+    assert!(state.level_db() > 20. * 0.99f64.log10() - 1e-3);
+
+    // Falls back just as slowly
+    state.push_rms(0., t0 + ms(400));
+    assert!(state.level_db() > -20.);
+}
+
+#[test]
+fn vu_follows_rms_of_samples() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::new(Ballistics::Vu);
+    state.push_samples(&[0.5, -0.5, 0.5, -0.5], t0);
+    assert!((state.level_db() - 20. * 0.5f64.log10()).abs() < 1e-3);
+    assert!(!state.is_clipped());
+}
+
+#[test]
+fn ppm_attacks_fast_and_releases_slowly() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::new(Ballistics::Ppm);
+    state.push_samples(&[0.], t0);
+    // Follows the peak, not the RMS of a block
+    state.push_samples(&[0., 1., 0., 0.], t0 + ms(10));
+    assert!(state.level_db() > 20. * 0.8f64.log10() - 1e-3);
+    state.push_samples(&[1.], t0 + ms(60));
+    assert!(state.level_db() > -0.01);
+
+    state.push_samples(&[0.], t0 + ms(1560));
+    assert!((state.level_db() + 20.).abs() < 0.05);
+}
+
+#[test]
+fn peak_holds_before_following_level() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::new(Ballistics::Direct).peak_hold(Duration::from_secs(1));
+    state.push_rms(amplitude(-6.), t0);
+    state.push_rms(amplitude(-20.), t0 + ms(900));
+    assert!((state.peak_db() + 6.).abs() < 1e-3);
+    state.push_rms(amplitude(-20.), t0 + ms(1000));
+    assert!((state.peak_db() + 20.).abs() < 1e-3);
+}
+
+#[test]
+fn level_meter_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<LevelMeter<'static>>();
+}
+
+#[test]
+fn meter_ignores_samples_which_are_not_finite() {
+    let t0 = Instant::now();
+    let mut state = LevelMeterState::default();
+    state.push_samples(&[0.5, f32::NAN], t0);
+    state.push_rms(f64::NAN, t0 + ms(10));
+    state.push_rms(f64::INFINITY, t0 + ms(20));
+    assert_eq!(state.level_db(), 20. * 0.5f64.log10());
+    assert!(!state.is_clipped());
+    state.push_samples(&[f32::NAN], t0 + ms(30));
+    state.push_rms(0.5, t0 + ms(40));
+    assert!(state.level_db().is_finite());
+}
@@ -0,0 +1,64 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+use test_case::test_case;
+use tui_bars::{PadMarker, XyPad};
+
+fn render(pad: XyPad, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    pad.render(area, &mut buffer);
+    buffer
+}
+
+#[test_case(0., 0.,   vec!["   │  ", "───▖──", "   │  ", "   │  "] ; "zero")]
+#[test_case(1., 1.,   vec!["   │ ▝", "───┼──", "   │  ", "   │  "] ; "top_right")]
+#[test_case(-1., -1., vec!["   │  ", "───┼──", "   │  ", "▖  │  "] ; "bottom_left")]
+#[test_case(9., -0.1, vec!["   │  ", "───┼──", "   │ ▝", "   │  "] ; "clamped")]
+#[test_case(f64::NAN, 0., vec!["   │  ", "───┼──", "   │  ", "   │  "] ; "nan")]
+fn pad_renders_point_with_quadrants(x: f64, y: f64, lines: Vec<&str>) {
+    assert_eq!(
+        render(XyPad::default().point(x, y), 6, 4),
+        Buffer::with_lines(lines)
+    );
+}
+
+#[test]
+fn pad_renders_point_with_braille() {
+    let pad = XyPad::default()
+        .range(10, 5)
+        .point(3, -3)
+        .marker(PadMarker::Braille)
+        .crosshair(false);
+    assert_eq!(render(pad, 2, 2), Buffer::with_lines(vec!["  ", " ⠄"]));
+}
+
+#[test]
+fn pad_renders_fading_trail() {
+    let pad = XyPad::default()
+        .point(1., 1.)
+        .trail([(-1., 1.), (-0.5, 1.), (0.5, 1.)])
+        .crosshair(false)
+        .trail_style(Style::default().fg(Color::Blue))
+        .marker_style(Style::default().fg(Color::Yellow));
+    let mut expected = Buffer::with_lines(vec!["▘▘ ▀"]);
+    expected.get_mut(0, 0).set_fg(Color::Blue).modifier = Modifier::DIM;
+    expected.get_mut(1, 0).set_fg(Color::Blue);
+    expected.get_mut(3, 0).set_fg(Color::Yellow);
+    assert_eq!(render(pad, 4, 1), expected);
+}
+
+#[test]
+fn pad_renders_crosshair_style() {
+    let pad = XyPad::default()
+        .point(-1., 1.)
+        .crosshair_style(Style::default().fg(Color::DarkGray));
+    // The marker replaces the crosshair in its cell, but keeps its style
+    let mut expected = Buffer::with_lines(vec!["▘┼", " │"]);
+    expected.set_style(Rect::new(0, 0, 2, 1), Style::default().fg(Color::DarkGray));
+    expected.get_mut(1, 1).set_fg(Color::DarkGray);
+    assert_eq!(render(pad, 2, 2), expected);
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "crossterm")]
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::StatefulWidget,
+};
+use test_case::test_case;
+use tui_bars::{RangeHandle, RangeSlider, RangeSliderState, SliderAction};
+
+fn render(slider: RangeSlider, state: &mut RangeSliderState) -> Buffer {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buffer = Buffer::empty(area);
+    slider.render(area, &mut buffer, state);
+    buffer
+}
+
+fn slider<'a>() -> RangeSlider<'a> {
+    RangeSlider::new(0., 100.)
+}
+
+#[test_case(25., 75.,  "  ▐████▌  " ; "half_cells")]
+#[test_case(0., 100.,  "██████████" ; "everything")]
+#[test_case(21.25, 30., "  🮋▏      " ; "eighths")]
+#[test_case(41.25, 47.5, "    ▊     " ; "within_one_cell")]
+#[test_case(50., 50.,  "     ▏    " ; "empty")]
+fn range_slider_renders_span(low: f64, high: f64, expected: &str) {
+    let mut state = RangeSliderState::new(low, high);
+    assert_eq!(
+        render(slider(), &mut state),
+        Buffer::with_lines(vec![expected])
+    );
+}
+
+#[test]
+fn range_slider_renders_vertically() {
+    let area = Rect::new(0, 0, 1, 4);
+    let mut buffer = Buffer::empty(area);
+    let mut state = RangeSliderState::new(12.5, 75.);
+    RangeSlider::new(0., 100.)
+        .direction(Direction::Vertical)
+        .render(area, &mut buffer, &mut state);
+    assert_eq!(buffer, Buffer::with_lines(vec!["▁", "█", "█", "▀"]));
+}
+
+#[test]
+fn range_slider_renders_readout() {
+    let mut state = RangeSliderState::new(20., 80.);
+    assert_eq!(
+        render(slider().readout(0).track_symbol("░"), &mut state),
+        Buffer::with_lines(vec!["░20 – 80▏░"])
+    );
+}
+
+#[test]
+fn range_slider_highlights_active_handle_when_focused() {
+    let slider = || slider().handle_style(Style::default().bg(Color::Yellow));
+    let mut state = RangeSliderState::new(25., 75.);
+    let mut expected = Buffer::with_lines(vec!["  ▐████▌  "]);
+    assert_eq!(render(slider(), &mut state), expected);
+
+    state.set_focused(true);
+    state.select(RangeHandle::High);
+    expected.get_mut(7, 0).set_bg(Color::Yellow);
+    assert_eq!(render(slider(), &mut state), expected);
+}
+
+#[test]
+fn range_slider_clamps_state_to_bounds() {
+    let mut state = RangeSliderState::new(-20., 120.);
+    render(slider(), &mut state);
+    assert_eq!((state.low(), state.high()), (0., 100.));
+}
+
+#[test]
+fn range_slider_ignores_input_before_first_render() {
+    let mut state = RangeSliderState::new(20., 80.);
+    assert!(!state.apply(SliderAction::Increase));
+    assert!(!state.press(3, 0));
+    assert_eq!((state.low(), state.high()), (20., 80.));
+}
+
+#[test_case(RangeHandle::Low, SliderAction::Increase,  (30., 80.) ; "low_increase")]
+#[test_case(RangeHandle::Low, SliderAction::Min,       (0., 80.)  ; "low_min")]
+#[test_case(RangeHandle::Low, SliderAction::Max,       (80., 80.) ; "low_max_stops_at_high")]
+#[test_case(RangeHandle::Low, SliderAction::PageIncrease, (80., 80.) ; "low_page_stops_at_high")]
+#[test_case(RangeHandle::High, SliderAction::Decrease, (20., 70.) ; "high_decrease")]
+#[test_case(RangeHandle::High, SliderAction::FineIncrease, (20., 81.) ; "high_fine_increase")]
+#[test_case(RangeHandle::High, SliderAction::Min,      (20., 20.) ; "high_min_stops_at_low")]
+#[test_case(RangeHandle::High, SliderAction::Zero,     (20., 20.) ; "high_zero_stops_at_low")]
+fn range_slider_moves_active_handle(
+    handle: RangeHandle,
+    action: SliderAction,
+    expected: (f64, f64),
+) {
+    let mut state = RangeSliderState::new(20., 80.);
+    state.select(handle);
+    render(slider(), &mut state);
+    assert!(state.apply(action));
+    assert_eq!((state.low(), state.high()), expected);
+}
+
+#[test_case(0, RangeHandle::Low, (0., 80.)                 ; "first_cell")]
+#[test_case(1, RangeHandle::Low, (1. / 9. * 100., 80.)     ; "left_of_low")]
+#[test_case(4, RangeHandle::Low, (4. / 9. * 100., 80.)     ; "closer_to_low")]
+#[test_case(6, RangeHandle::High, (20., 6. / 9. * 100.)    ; "closer_to_high")]
+#[test_case(9, RangeHandle::High, (20., 100.)              ; "last_cell")]
+fn range_slider_moves_closer_handle_on_click(x: u16, handle: RangeHandle, expected: (f64, f64)) {
+    let mut state = RangeSliderState::new(20., 80.);
+    render(slider(), &mut state);
+    assert!(state.press(x, 0));
+    assert_eq!(state.active(), handle);
+    assert_eq!((state.low(), state.high()), expected);
+}
+
+#[test]
+fn range_slider_drag_stops_at_other_handle() {
+    let mut state = RangeSliderState::new(20., 60.);
+    render(slider().step(10.), &mut state);
+    assert!(state.press(3, 0));
+    assert!(state.is_dragging());
+    assert!(state.drag(9, 0));
+    assert_eq!((state.low(), state.high()), (60., 60.));
+
+    state.release();
+    assert!(!state.drag(0, 0));
+    assert_eq!((state.low(), state.high()), (60., 60.));
+}
+
+#[test]
+fn range_slider_keeps_fine_steps_on_render() {
+    let mut state = RangeSliderState::new(20., 80.);
+    render(slider().step(10.), &mut state);
+    assert!(state.apply(SliderAction::FineIncrease));
+    render(slider().step(10.), &mut state);
+    assert_eq!((state.low(), state.high()), (21., 80.));
+}
+
+#[test]
+fn range_slider_ignores_nan() {
+    let mut state = RangeSliderState::new(20., 80.);
+    state.set_high(f64::NAN);
+    state.set_low(f64::NAN);
+    render(slider(), &mut state);
+    assert_eq!((state.low(), state.high()), (20., 80.));
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn range_slider_switches_handle_with_tab() {
+    let mut state = RangeSliderState::new(20., 80.);
+    render(slider(), &mut state);
+    assert!(state.handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+    assert_eq!(state.active(), RangeHandle::High);
+    assert!(state.handle_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)));
+    assert_eq!((state.low(), state.high()), (20., 90.));
+}
+
+#[test]
+fn range_slider_state_equals_after_identical_render() {
+    let (mut first, mut second) = (
+        RangeSliderState::new(20., 80.),
+        RangeSliderState::new(20., 80.),
+    );
+    render(slider(), &mut first);
+    render(slider(), &mut second);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn range_slider_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<RangeSlider<'static>>();
+}
+
+#[test]
+fn range_slider_state_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<RangeSliderState>();
+}
+
+#[test_case(f64::NAN, 1.      ; "nan_min")]
+#[test_case(0.,       f64::NAN ; "nan_max")]
+#[test_case(1.,       0.      ; "inverted")]
+fn range_slider_ignores_input_with_invalid_bounds(min: f64, max: f64) {
+    let mut state = RangeSliderState::new(0.2, 0.8);
+    state.select(RangeHandle::High);
+    assert_eq!(
+        render(RangeSlider::new(min, max), &mut state),
+        Buffer::with_lines(vec!["          "])
+    );
+    assert!(!state.apply(SliderAction::Increase));
+    assert!(!state.press(3, 0));
+    assert_eq!((state.low(), state.high()), (0.2, 0.8));
+}
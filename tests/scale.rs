@@ -0,0 +1,76 @@
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use test_case::test_case;
+use tui_bars::{Decibel, Linear, PiecewiseLinear, Scale, Sqrt, SymLog, ValueBar};
+
+fn render(bar: ValueBar) -> Buffer {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buffer = Buffer::empty(area);
+    bar.render(area, &mut buffer);
+    buffer
+}
+
+fn calibration() -> PiecewiseLinear {
+    PiecewiseLinear::new([(-10., -1.), (0., 0.), (2., 0.5), (10., 1.)])
+}
+
+#[test_case(&Linear,              5.,   0.5   ; "linear")]
+#[test_case(&Linear,              -10., -1.   ; "linear_negative_end")]
+#[test_case(&Sqrt,                2.5,  0.5   ; "sqrt")]
+#[test_case(&Sqrt,                -2.5, -0.5  ; "sqrt_negative")]
+#[test_case(&Decibel::new(-40.),  1.,   0.5   ; "decibel_minus_20db")]
+#[test_case(&Decibel::new(-60.),  0.01, 0.    ; "decibel_floor")]
+#[test_case(&Decibel::new(-60.),  -10., -1.   ; "decibel_negative_0db")]
+#[test_case(&SymLog::new(1.),     0.,   0.    ; "symlog_zero")]
+#[test_case(&SymLog::new(-1.),    5.,   0.5   ; "symlog_invalid_is_linear")]
+#[test_case(&calibration(),       1.,   0.25  ; "piecewise_between_points")]
+#[test_case(&calibration(),       6.,   0.75  ; "piecewise_second_segment")]
+#[test_case(&calibration(),       -20., -1.   ; "piecewise_clamped")]
+fn scale_maps_value_to_position(scale: &dyn Scale, value: f64, position: f64) {
+    let range = 10.;
+    assert!((scale.position(value, range) - position).abs() < 1e-9);
+}
+
+#[test_case(&Linear              ; "linear")]
+#[test_case(&Sqrt                ; "sqrt")]
+#[test_case(&Decibel::default()  ; "decibel")]
+#[test_case(&SymLog::new(0.1)    ; "symlog")]
+#[test_case(&calibration()       ; "piecewise")]
+fn scale_maps_position_back_to_value(scale: &dyn Scale) {
+    let range = 10.;
+    for value in [-10., -3., -0.5, 0.5, 3., 10.] {
+        let position = scale.position(value, range);
+        assert!((scale.value(position, range) - value).abs() < 1e-9);
+    }
+}
+
+#[test_case(ValueBar::default().scale(Sqrt),               "     ███▏ " ; "sqrt")]
+#[test_case(ValueBar::default().scale(Decibel::new(-40.)), "     ████▏" ; "decibel")]
+#[test_case(ValueBar::default().scale(calibration()),      "     ███▏ " ; "piecewise")]
+fn valuebar_renders_with_scale(bar: ValueBar, line: &str) {
+    assert_eq!(
+        render(bar.value(4.).range(10.)),
+        Buffer::with_lines(vec![line])
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct Inverted;
+
+impl Scale for Inverted {
+    fn position(&self, value: f64, range: f64) -> f64 {
+        -value / range
+    }
+
+    fn value(&self, position: f64, range: f64) -> f64 {
+        -position * range
+    }
+}
+
+#[test]
+fn valuebar_renders_with_custom_scale() {
+    let bar = ValueBar::default().value(5.).range(10.).scale(Inverted);
+    let geometry = bar.geometry(Rect::new(0, 0, 10, 1));
+    assert_eq!(geometry.value_to_position(5.), (2, 4));
+    assert_eq!(geometry.position_to_value(0, 0), 10.);
+    assert_eq!(render(bar), Buffer::with_lines(vec!["  ▐██     "]));
+}
@@ -0,0 +1,268 @@
+#[cfg(feature = "crossterm")]
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, StatefulWidget},
+};
+use test_case::test_case;
+use tui_bars::{Slider, SliderAction, SliderState, ValueBar};
+
+const RANGE: f64 = 5.;
+
+fn render(slider: Slider, state: &mut SliderState) -> Buffer {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buffer = Buffer::empty(area);
+    slider.render(area, &mut buffer, state);
+    buffer
+}
+
+fn slider<'a>() -> Slider<'a> {
+    Slider::new(ValueBar::default().range(RANGE))
+}
+
+#[cfg(feature = "crossterm")]
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+#[test]
+fn slider_renders_value_of_state() {
+    let mut state = SliderState::new(-1.5);
+    assert_eq!(
+        render(slider(), &mut state),
+        Buffer::with_lines(vec!["   ▐█     "; 3])
+    );
+}
+
+#[test]
+fn slider_ignores_mouse_before_first_render() {
+    let mut state = SliderState::new(1.);
+    assert!(!state.press(2, 1));
+    assert_eq!(state.value(), 1.);
+}
+
+#[test_case(0, -RANGE                       ; "first_cell")]
+#[test_case(3, (2. * (3. / 9.) - 1.) * RANGE ; "negative_cell")]
+#[test_case(7, (2. * (7. / 9.) - 1.) * RANGE ; "positive_cell")]
+#[test_case(9, RANGE                         ; "last_cell")]
+fn slider_jumps_to_clicked_cell(x: u16, value: f64) {
+    let mut state = SliderState::default();
+    render(slider(), &mut state);
+    assert!(state.press(x, 1));
+    assert_eq!(state.value(), value);
+}
+
+#[test]
+fn slider_ignores_clicks_outside_of_block() {
+    let mut state = SliderState::default();
+    render(
+        Slider::new(
+            ValueBar::default()
+                .range(RANGE)
+                .block(Block::default().borders(Borders::ALL)),
+        ),
+        &mut state,
+    );
+    assert!(!state.press(0, 1));
+    assert!(!state.press(4, 0));
+    assert!(!state.is_dragging());
+}
+
+#[test]
+#[cfg(feature = "crossterm")]
+fn slider_follows_drag_until_release() {
+    let mut state = SliderState::default();
+    render(slider(), &mut state);
+    assert!(state.handle_mouse_event(mouse(MouseEventKind::Down(MouseButton::Left), 6, 0)));
+    assert_eq!(state.value(), (2. * (6. / 9.) - 1.) * RANGE);
+    assert!(state.handle_mouse_event(mouse(MouseEventKind::Drag(MouseButton::Left), 2, 7)));
+    assert_eq!(state.value(), (2. * (2. / 9.) - 1.) * RANGE);
+    // Dragging beyond the slider clamps to its end
+    assert!(state.handle_mouse_event(mouse(MouseEventKind::Drag(MouseButton::Left), 30, 1)));
+    assert_eq!(state.value(), RANGE);
+    assert!(!state.handle_mouse_event(mouse(MouseEventKind::Up(MouseButton::Left), 30, 1)));
+    assert!(!state.handle_mouse_event(mouse(MouseEventKind::Drag(MouseButton::Left), 0, 1)));
+    assert_eq!(state.value(), RANGE);
+}
+
+#[test]
+fn slider_quantizes_to_step() {
+    let mut state = SliderState::default();
+    render(slider().step(2.), &mut state);
+    state.press(7, 1);
+    assert_eq!(state.value(), 2.);
+    state.set_value(-3.1);
+    assert_eq!(state.value(), -4.);
+}
+
+#[test]
+fn slider_clamps_state_to_range() {
+    let mut state = SliderState::new(12.);
+    render(slider(), &mut state);
+    assert_eq!(state.value(), RANGE);
+    state.set_value(-12.);
+    assert_eq!(state.value(), -RANGE);
+}
+
+#[test]
+fn slider_ignores_keys_before_first_render() {
+    let mut state = SliderState::default();
+    assert!(!state.apply(SliderAction::Max));
+    assert_eq!(state.value(), 0.);
+}
+
+#[test_case(SliderAction::Increase,     1.  ; "increase")]
+#[test_case(SliderAction::Decrease,     -1. ; "decrease")]
+#[test_case(SliderAction::FineIncrease, 0.1 ; "fine_increase")]
+#[test_case(SliderAction::FineDecrease, -0.1 ; "fine_decrease")]
+#[test_case(SliderAction::PageIncrease, RANGE ; "page_increase")]
+#[test_case(SliderAction::PageDecrease, -RANGE ; "page_decrease")]
+#[test_case(SliderAction::Min,          -RANGE ; "min")]
+#[test_case(SliderAction::Max,          RANGE ; "max")]
+fn slider_moves_by_one_cell_per_key_by_default(action: SliderAction, value: f64) {
+    let mut state = SliderState::default();
+    render(slider(), &mut state);
+    assert!(state.apply(action));
+    assert_eq!(state.value(), value);
+}
+
+#[test]
+fn slider_moves_by_configured_steps() {
+    let mut state = SliderState::new(3.);
+    render(slider().step(0.5).page_step(2.).fine_step(0.25), &mut state);
+    state.apply(SliderAction::Decrease);
+    assert_eq!(state.value(), 2.5);
+    state.apply(SliderAction::PageDecrease);
+    assert_eq!(state.value(), 0.5);
+    state.apply(SliderAction::FineDecrease);
+    assert_eq!(state.value(), 0.25);
+    assert!(state.apply(SliderAction::Zero));
+    assert!(!state.apply(SliderAction::Zero));
+    assert_eq!(state.value(), 0.);
+}
+
+#[test]
+fn slider_keeps_fine_steps_on_render() {
+    let mut state = SliderState::default();
+    render(slider().step(1.), &mut state);
+    assert!(state.apply(SliderAction::FineIncrease));
+    render(slider().step(1.), &mut state);
+    assert_eq!(state.value(), 0.1);
+    assert!(state.apply(SliderAction::Increase));
+    assert_eq!(state.value(), 1.);
+}
+
+#[test]
+fn slider_renders_focused_style() {
+    let slider = || {
+        slider()
+            .style(Style::default().fg(Color::DarkGray))
+            .focused_style(Style::default().fg(Color::Yellow))
+    };
+    let mut state = SliderState::new(1.5);
+    let mut expected = Buffer::with_lines(vec!["     █▌   "; 3]);
+    expected.set_style(expected.area, Style::default().fg(Color::DarkGray));
+    assert_eq!(render(slider(), &mut state), expected);
+
+    state.set_focused(true);
+    expected.set_style(expected.area, Style::default().fg(Color::Yellow));
+    assert_eq!(render(slider(), &mut state), expected);
+}
+
+#[cfg(feature = "crossterm")]
+#[test_case(KeyCode::Right, KeyModifiers::NONE,  Some(SliderAction::Increase) ; "right")]
+#[test_case(KeyCode::Down, KeyModifiers::NONE,   Some(SliderAction::Decrease) ; "down")]
+#[test_case(KeyCode::Up, KeyModifiers::SHIFT,    Some(SliderAction::FineIncrease) ; "shift_up")]
+#[test_case(KeyCode::PageDown, KeyModifiers::NONE, Some(SliderAction::PageDecrease) ; "page_down")]
+#[test_case(KeyCode::Home, KeyModifiers::NONE,   Some(SliderAction::Min) ; "home")]
+#[test_case(KeyCode::End, KeyModifiers::NONE,    Some(SliderAction::Max) ; "end")]
+#[test_case(KeyCode::Char('0'), KeyModifiers::NONE, Some(SliderAction::Zero) ; "zero")]
+#[test_case(KeyCode::Char('q'), KeyModifiers::NONE, None ; "unrelated")]
+fn slider_maps_crossterm_keys(
+    code: KeyCode,
+    modifiers: KeyModifiers,
+    action: Option<SliderAction>,
+) {
+    assert_eq!(
+        SliderAction::from_key_event(KeyEvent::new(code, modifiers)),
+        action
+    );
+}
+
+#[test]
+fn slider_ignores_drag_after_shrinking_to_nothing() {
+    let mut state = SliderState::default();
+    render(slider(), &mut state);
+    state.press(7, 1);
+    let value = state.value();
+    let area = Rect::new(4, 0, 0, 3);
+    slider().render(area, &mut Buffer::empty(area), &mut state);
+    assert!(!state.drag(2, 1));
+    assert_eq!(state.value(), value);
+}
+
+#[test]
+fn slider_keeps_f64_precision() {
+    let value = 1_000_000.000_001;
+    let mut state = SliderState::new(value);
+    render(
+        Slider::new(ValueBar::default().range(2_000_000f64)),
+        &mut state,
+    );
+    assert_eq!(state.value(), value);
+}
+
+#[test_case(-1.      ; "negative")]
+#[test_case(f64::NAN ; "nan")]
+fn slider_ignores_input_with_invalid_range(range: f64) {
+    let mut state = SliderState::new(1.);
+    let slider = || Slider::new(ValueBar::default().range(range));
+    let mut lines = vec!["╌╌╌╌╌╌╌╌╌╌"; 3];
+    lines[1] = "╌╌╌╌--╌╌╌╌";
+    assert_eq!(render(slider(), &mut state), Buffer::with_lines(lines));
+    assert!(!state.press(3, 1));
+    assert!(!state.apply(SliderAction::Max));
+    assert_eq!(state.value(), 1.);
+}
+
+#[test]
+fn slider_state_equals_after_identical_render() {
+    let (mut first, mut second) = (SliderState::new(1.), SliderState::new(1.));
+    render(slider(), &mut first);
+    render(slider(), &mut second);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn slider_state_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SliderState>();
+}
+
+#[test]
+fn slider_states_differ_by_scale() {
+    let (mut first, mut second) = (SliderState::new(1.), SliderState::new(1.));
+    render(slider(), &mut first);
+    render(
+        Slider::new(ValueBar::default().range(RANGE).log_scale(1.)),
+        &mut second,
+    );
+    assert_ne!(first, second);
+}
+
+#[test]
+fn slider_ignores_nan() {
+    let mut state = SliderState::new(1.);
+    render(slider(), &mut state);
+    state.set_value(f64::NAN);
+    assert_eq!(state.value(), 1.);
+    assert!(state.apply(SliderAction::Increase));
+    assert_eq!(state.value(), 2.);
+}
@@ -0,0 +1,91 @@
+use std::collections::VecDeque;
+
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    widgets::{Block, Borders},
+    Terminal,
+};
+use tui_bars::{BipolarSparkline, Symbols};
+
+fn assert_renders(widget: BipolarSparkline, width: u16, expected: Buffer) {
+    let backend = TestBackend::new(width, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|f| {
+            f.render_widget(widget, f.size());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer(&expected)
+}
+
+#[test]
+fn sparkline_renders_samples_around_zero_line() {
+    assert_renders(
+        BipolarSparkline::default()
+            .data([0., 0.5, 1., 2., -0.5, -1., -2.])
+            .range(2.),
+        7,
+        Buffer::with_lines(vec!["  ▁█   ", "▁▄██   ", "    ▀██", "      █"]),
+    )
+}
+
+#[test]
+fn sparkline_drops_oldest_samples_if_too_narrow() {
+    assert_renders(
+        BipolarSparkline::default()
+            .data([2., 2., -2., 1.])
+            .range(2.),
+        3,
+        Buffer::with_lines(vec!["█ ▁", "█ █", " █ ", " █ "]),
+    )
+}
+
+#[test]
+fn sparkline_right_aligns_few_samples_and_uses_largest_as_default_range() {
+    let mut ring = VecDeque::from([4., -4.]);
+    ring.push_back(2.);
+    ring.pop_front();
+    assert_renders(
+        BipolarSparkline::default()
+            .data(ring.iter().copied())
+            .block(Block::default().borders(Borders::LEFT | Borders::RIGHT)),
+        6,
+        Buffer::with_lines(vec!["│   ▁│", "│   █│", "│  █ │", "│  █ │"]),
+    )
+}
+
+#[test]
+fn sparkline_packs_two_samples_per_cell_with_braille() {
+    assert_renders(
+        BipolarSparkline::default()
+            .data([9., 0.5, 1., 2., -0.5, -1., -2.])
+            .range(2.)
+            .symbols(Symbols::Braille),
+        4,
+        Buffer::with_lines(vec!["⢸ ⡇ ", "⢸⣼⡇ ", "  ⠘⣿", "   ⢸"]),
+    )
+}
+
+#[test]
+fn sparkline_renders_nan_as_no_data() {
+    assert_renders(
+        BipolarSparkline::default()
+            .data([1., f32::NAN, -1.])
+            .range(2.),
+        3,
+        Buffer::with_lines(vec!["▁╎ ", "█╎ ", " ╎█", " ╎ "]),
+    )
+}
+
+#[test]
+fn sparkline_renders_nan_as_no_data_with_braille() {
+    assert_renders(
+        BipolarSparkline::default()
+            .data([f32::NAN, f32::NAN, 1., f32::NAN])
+            .range(2.)
+            .symbols(Symbols::Braille),
+        2,
+        Buffer::with_lines(vec!["╎⠨", "╎⡯", "╎⠨", "╎⠨"]),
+    )
+}
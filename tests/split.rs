@@ -0,0 +1,97 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Color, Style},
+    widgets::Widget,
+};
+use tui_bars::{SplitBar, Symbols};
+
+const RANGE: f32 = 5.;
+
+fn render(bar: SplitBar, width: u16, height: u16) -> Buffer {
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    bar.render(area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn split_renders_each_value_in_its_half_with_label() {
+    let bar = SplitBar::default()
+        .values(2.5, -2.5)
+        .range(RANGE)
+        .labels("rx", "tx");
+    assert_eq!(
+        render(bar, 10, 2),
+        Buffer::with_lines(vec!["    rx█▌  ", "  ▐█tx    "])
+    );
+}
+
+#[test]
+fn split_merges_shared_middle_row() {
+    let (red, blue) = (
+        Style::default().fg(Color::Red),
+        Style::default().fg(Color::Blue),
+    );
+    let bar = SplitBar::default()
+        .values(2.5, 1.)
+        .range(RANGE)
+        .styles(red, blue);
+    let mut expected = Buffer::with_lines(vec!["     ██▌  ", "     ▀▀▘  ", "     █▏   "]);
+    expected.set_style(Rect::new(0, 0, 10, 1), red);
+    expected.set_style(Rect::new(5, 1, 3, 1), red);
+    expected.get_mut(5, 1).set_bg(Color::Blue);
+    expected.set_style(Rect::new(0, 2, 10, 1), blue);
+    assert_eq!(render(bar, 10, 3), expected);
+}
+
+#[test]
+fn split_renders_both_values_in_single_row() {
+    let bar = SplitBar::default().values(RANGE, -RANGE).range(RANGE);
+    assert_eq!(render(bar, 10, 1), Buffer::with_lines(vec!["▄▄▄▄▄▀▀▀▀▀"]));
+}
+
+#[test]
+fn split_renders_vertically() {
+    let bar = SplitBar::default()
+        .values(RANGE, -RANGE)
+        .range(RANGE)
+        .direction(Direction::Vertical);
+    assert_eq!(
+        render(bar, 3, 4),
+        Buffer::with_lines(vec!["█▌ ", "█▌ ", " ▐█", " ▐█"])
+    );
+}
+
+#[test]
+fn split_renders_half_cells_and_labels_in_single_row() {
+    let bar = SplitBar::default()
+        .values(2.5, -1.5)
+        .range(RANGE)
+        .labels("rx", "tx");
+    assert_eq!(render(bar, 10, 1), Buffer::with_lines(vec!["  rx▄tx▘  "]));
+}
+
+#[test]
+fn split_draws_first_value_where_both_end_in_shared_cell() {
+    let bar = SplitBar::default().values(2.5, 2.).range(RANGE);
+    assert_eq!(render(bar, 10, 1), Buffer::with_lines(vec!["     ▀▀▘  "]));
+}
+
+#[test]
+fn split_accepts_custom_symbols() {
+    let bar = SplitBar::default()
+        .values(2.5, -2.5)
+        .range(RANGE)
+        .symbols(Symbols::Ascii);
+    assert_eq!(
+        render(bar, 10, 2),
+        Buffer::with_lines(vec!["     ##=  ", "  =##     "])
+    );
+}
+
+#[test]
+fn split_bar_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SplitBar<'static>>();
+}
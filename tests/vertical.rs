@@ -3,12 +3,12 @@ use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
     layout::Direction,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::{Block, Borders},
     Terminal,
 };
 use test_case::test_case;
-use tui_bars::ValueBar;
+use tui_bars::{Align, NegativeFill, Symbols, Thickness, ValueBar};
 
 const TERMINAL_HEIGHT: u16 = 10;
 const RANGE: f32 = 5.;
@@ -302,3 +302,230 @@ fn vertical_renders_with_style_and_label(color: Color) {
         expected,
     )
 }
+
+#[test_case(0.,         "          " ; "zero")]
+#[test_case(1. / 8.,    "    ⣀     " ; "positive_one_eighths")]
+#[test_case(3. / 8.,    "    ⣤     " ; "positive_three_eighths")]
+#[test_case(5. / 8.,    "    ⣶     " ; "positive_five_eighths")]
+#[test_case(7. / 8.,    "    ⣿     " ; "positive_seven_eighths")]
+#[test_case(RANGE*0.5,  "  ⣤⣿⣿     " ; "positive_range_50_percent")]
+#[test_case(RANGE*1.0,  "⣿⣿⣿⣿⣿     " ; "positive_range_full")]
+#[test_case(-1. / 8.,   "     ⠉    " ; "negative_one_eighths")]
+#[test_case(-3. / 8.,   "     ⠛    " ; "negative_three_eighths")]
+#[test_case(-5. / 8.,   "     ⠿    " ; "negative_five_eighths")]
+#[test_case(-RANGE*0.5, "     ⣿⣿⠛  " ; "negative_range_50_percent")]
+#[test_case(-RANGE*1.0, "     ⣿⣿⣿⣿⣿" ; "negative_range_full")]
+fn vertical_renders_value_with_braille(value: f32, col: &str) {
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .symbols(Symbols::Braille),
+        Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect()),
+    )
+}
+
+#[test_case(Symbols::Blocks, 3. / 8.,        "    ▃     " ; "blocks_positive_three_eighths")]
+#[test_case(Symbols::Blocks, -1. / 8.,       "     ▔    " ; "blocks_negative_one_eighths")]
+#[test_case(Symbols::Blocks, -3. / 8.,       "     ▀    " ; "blocks_negative_three_eighths")]
+#[test_case(Symbols::Blocks, -7. / 8.,       "     █    " ; "blocks_negative_seven_eighths")]
+#[test_case(Symbols::Blocks, -RANGE*0.3,     "     █▀   " ; "blocks_negative_range_30_percent")]
+#[test_case(Symbols::HalfBlocks, 2. / 8.,    "    ▄     " ; "half_blocks_positive_two_eighths")]
+#[test_case(Symbols::HalfBlocks, 6. / 8.,    "    █     " ; "half_blocks_positive_six_eighths")]
+#[test_case(Symbols::HalfBlocks, RANGE*0.3,  "   ▄█     " ; "half_blocks_positive_range_30_percent")]
+#[test_case(Symbols::HalfBlocks, -2. / 8.,   "     ▀    " ; "half_blocks_negative_two_eighths")]
+#[test_case(Symbols::HalfBlocks, -RANGE*0.3, "     █▀   " ; "half_blocks_negative_range_30_percent")]
+#[test_case(Symbols::Ascii, 1. / 8.,         "    -     " ; "ascii_positive_one_eighths")]
+#[test_case(Symbols::Ascii, RANGE*0.3,       "   =#     " ; "ascii_positive_range_30_percent")]
+#[test_case(Symbols::Ascii, -3. / 8.,        "     =    " ; "ascii_negative_three_eighths")]
+#[test_case(Symbols::Ascii, -RANGE*1.0,      "     #####" ; "ascii_negative_range_full")]
+fn vertical_renders_value_with_symbols(symbols: Symbols, value: f32, col: &str) {
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .symbols(symbols),
+        Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect()),
+    )
+}
+
+#[test_case(-1. / 8.,   "     ▇    ", Some(5) ; "negative_one_eighths")]
+#[test_case(-3. / 8.,   "     ▅    ", Some(5) ; "negative_three_eighths")]
+#[test_case(-7. / 8.,   "     ▁    ", Some(5) ; "negative_seven_eighths")]
+#[test_case(-8. / 8.,   "     █    ", None    ; "negative_eight_eighths")]
+#[test_case(-RANGE*0.3, "     █▄   ", Some(6) ; "negative_range_30_percent")]
+#[test_case(RANGE*0.3,  "   ▄█     ", None    ; "positive_range_30_percent")]
+fn vertical_renders_inverted_negative_fill(value: f32, col: &str, inverted: Option<u16>) {
+    let mut expected = Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect());
+    if let Some(y) = inverted {
+        for x in 0..5 {
+            expected.get_mut(x, y).modifier = Modifier::REVERSED;
+        }
+    }
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .negative_fill(NegativeFill::Inverted),
+        expected,
+    )
+}
+
+#[test_case(Thickness::Cells(1), Align::End, RANGE*0.3, vec![
+    "     ",
+    "     ",
+    "     ",
+    "    ▄",
+    "    █",
+] ; "one_cell_at_end")]
+#[test_case(Thickness::HalfCells(1), Align::Start, RANGE*0.3, vec![
+    "     ",
+    "     ",
+    "     ",
+    "▖    ",
+    "▌    ",
+] ; "one_half_cell_at_start")]
+#[test_case(Thickness::HalfCells(3), Align::Center, RANGE*0.3, vec![
+    "     ",
+    "     ",
+    "     ",
+    " ▗▄  ",
+    " ▐█  ",
+] ; "three_half_cells_centered")]
+fn vertical_renders_positive_value_with_thickness(
+    thickness: Thickness,
+    align: Align,
+    value: f32,
+    lines: Vec<&str>,
+) {
+    let mut lines: Vec<String> = lines.into_iter().map(|l| format!("{l:5}")).collect();
+    lines.resize(TERMINAL_HEIGHT as usize, "     ".to_owned());
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .thickness(thickness)
+            .align(align),
+        Buffer::with_lines(lines),
+    )
+}
+
+#[test]
+fn vertical_renders_negative_value_with_half_cell_thickness() {
+    assert_renders(
+        vertical_value_bar()
+            .value(-RANGE * 0.3)
+            .range(RANGE)
+            .thickness(Thickness::HalfCells(1))
+            .align(Align::End),
+        Buffer::with_lines(vec![
+            "     ", "     ", "     ", "     ", "     ", "    ▐", "    ▝", "     ", "     ",
+            "     ",
+        ]),
+    )
+}
+
+#[test]
+fn vertical_renders_label_centered_over_thickness() {
+    assert_renders(
+        vertical_value_bar()
+            .value(RANGE * 0.3)
+            .range(RANGE)
+            .label("AB")
+            .thickness(Thickness::Cells(3))
+            .align(Align::Start),
+        Buffer::with_lines(vec![
+            "     ",
+            "     ",
+            "     ",
+            "▄▄▄  ",
+            "AB█  ",
+            "     ",
+            "     ",
+            "     ",
+            "     ",
+            "     ",
+        ]),
+    )
+}
+
+#[test]
+fn vertical_renders_track_with_label() {
+    let mut expected = Buffer::with_lines(
+        "···▄█·····"
+            .chars()
+            .map(|c| c.to_string().repeat(5))
+            .collect(),
+    );
+    for x in 1..4 {
+        expected
+            .get_mut(x, 4)
+            .set_char("ABC".chars().nth(x as usize - 1).unwrap());
+    }
+    let area = expected.area();
+    for (x, y) in (area.left()..area.right()).cartesian_product(area.top()..area.bottom()) {
+        let cell = expected.get_mut(x, y);
+        if cell.symbol == "·" {
+            cell.set_fg(Color::DarkGray);
+        }
+    }
+    assert_renders(
+        vertical_value_bar()
+            .value(RANGE * 0.3)
+            .range(RANGE)
+            .label("ABC")
+            .track_symbol("·")
+            .track_style(Style::default().fg(Color::DarkGray)),
+        expected,
+    )
+}
+
+#[test_case(0.,         "    ─     " ; "zero")]
+#[test_case(RANGE*0.3,  "   ▄█     " ; "positive_range_30_percent")]
+#[test_case(-RANGE*0.3, "    ─█▀   " ; "negative_range_30_percent")]
+fn vertical_renders_zero_line(value: f32, col: &str) {
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .zero_line("─"),
+        Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect()),
+    )
+}
+
+#[test_case(0.,             "─         " ; "zero")]
+#[test_case(RANGE*4./36.,   "─   ▁▀    " ; "positive_four_eighths")]
+#[test_case(RANGE*1.0,      "─████▀    " ; "positive_range_full")]
+#[test_case(-RANGE*4./36.,  "─    ▄    " ; "negative_four_eighths")]
+#[test_case(-RANGE*10./36., "─    ▄🮅   " ; "negative_ten_eighths")]
+#[test_case(-RANGE*1.0,     "─    ▄████" ; "negative_range_full")]
+fn vertical_renders_value_with_zero_in_middle_of_odd_height(value: f32, col: &str) {
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .block(Block::default().borders(Borders::TOP)),
+        Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect()),
+    )
+}
+
+#[test_case(vertical_value_bar().value(f32::NAN).range(RANGE) ; "nan")]
+#[test_case(vertical_value_bar().value(1.).range(0.) ; "zero_range")]
+fn vertical_renders_no_data(bar: ValueBar) {
+    let mut lines = vec!["╎╎╎╎╎"; TERMINAL_HEIGHT as usize];
+    lines[4] = "╎--╎╎";
+    assert_renders(bar, Buffer::with_lines(lines))
+}
+
+#[test_case(2.  ; "positive")]
+#[test_case(-2. ; "negative")]
+fn vertical_renders_mirrored_value(value: f32) {
+    let col = "  ▁████   ";
+    assert_renders(
+        vertical_value_bar()
+            .value(value)
+            .range(RANGE)
+            .mirrored(true),
+        Buffer::with_lines(col.chars().map(|c| c.to_string().repeat(5)).collect()),
+    )
+}